@@ -0,0 +1,21 @@
+use rui::*;
+
+fn main() {
+    rui(state(
+        || false,
+        |closing, _| {
+            button("Close", move |cx| cx[closing] = true)
+                .padding(Auto)
+                .alert(
+                    closing,
+                    "Save changes before closing?",
+                    "Your changes will be lost if you don't save them.",
+                    vec![
+                        AlertButton::new("Don't Save", |_| println!("don't save")),
+                        AlertButton::cancel("Cancel", |_| println!("cancel")),
+                        AlertButton::default_action("Save", |_| println!("save")),
+                    ],
+                )
+        },
+    ));
+}
@@ -0,0 +1,17 @@
+use rui::*;
+
+// Right-click the rectangle, or long-press it on a touch screen.
+
+fn main() {
+    rui(rectangle()
+        .corner_radius(5.0)
+        .color(AZURE_HIGHLIGHT)
+        .padding(Auto)
+        .context_menu((
+            command("Cut").action(|| println!("cut")),
+            command("Copy").action(|| println!("copy")),
+            command("Paste")
+                .enabled(|_| false)
+                .action(|| println!("paste")),
+        )));
+}
@@ -0,0 +1,52 @@
+use rui::*;
+
+/// Draws audio samples as bars.
+struct Waveform {
+    samples: Vec<f32>,
+}
+
+impl CustomView for Waveform {
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        let rect = args.cx.layout_rect(id);
+        let paint = args.vger.color_paint(AZURE_HIGHLIGHT);
+        let width = rect.width() / self.samples.len() as f32;
+
+        for (i, sample) in self.samples.iter().enumerate() {
+            let height = sample.abs() * rect.height();
+            let origin = [i as f32 * width, (rect.height() - height) / 2.0];
+            args.vger.fill_rect(
+                LocalRect::new(origin.into(), [width * 0.8, height].into()),
+                0.0,
+                paint,
+            );
+        }
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        if cx.layout_rect(id).contains(pt) {
+            Some(id)
+        } else {
+            None
+        }
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        _cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        let aid = id.access_id();
+        let mut builder = accesskit::NodeBuilder::new(accesskit::Role::Image);
+        builder.set_name("Waveform");
+        nodes.push((aid, builder));
+        Some(aid)
+    }
+}
+
+fn main() {
+    let samples = (0..64).map(|i| (i as f32 * 0.4).sin()).collect();
+    rui(custom(Waveform { samples })
+        .tap(|_| println!("tapped the waveform"))
+        .padding(Auto))
+}
@@ -0,0 +1,31 @@
+use rui::*;
+
+#[derive(Lens)]
+struct Mixer {
+    gain: f32,
+    pan: f32,
+}
+
+#[derive(Lens)]
+struct App {
+    mixer: Mixer,
+}
+
+fn main() {
+    rui(state(
+        || App {
+            mixer: Mixer {
+                gain: 0.5,
+                pan: 0.5,
+            },
+        },
+        |s, cx| {
+            vstack((
+                format!("gain {:.2}", cx[s].mixer.gain).padding(Auto),
+                hslider(bind(s, App::mixer().then(Mixer::gain()))).padding(Auto),
+                format!("pan {:.2}", cx[s].mixer.pan).padding(Auto),
+                hslider(bind(s, App::mixer().then(Mixer::pan()))).padding(Auto),
+            ))
+        },
+    ));
+}
@@ -23,7 +23,13 @@ fn main() {
             "Drag (inside rectangle)".padding(Auto),
         )),
         "Handle key pressed"
-            .key(|cx, key| println!("key: {:?}, key modifiers state: {:?}", key, cx.key_mods))
+            .key_down(|_, key, mods, repeat| {
+                println!(
+                    "key down: {:?}, key modifiers state: {:?}, repeat: {}",
+                    key, mods, repeat
+                )
+            })
+            .key_up(|_, key, mods| println!("key up: {:?}, key modifiers state: {:?}", key, mods))
             .padding(Auto),
     )));
 }
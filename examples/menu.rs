@@ -8,7 +8,10 @@ fn main() {
         circle()
             .color(RED_HIGHLIGHT)
             .padding(Auto)
-            .command("File:New", Some(HotKey::KeyN), |_| println!("new")),
+            .command("File:New", Some(HotKey::KeyN.into()), |_| println!("new"))
+            .command("File:Save As", "Primary+Shift+S".parse().ok(), |_| {
+                println!("save as")
+            }),
         rectangle()
             .corner_radius(5.0)
             .color(AZURE_HIGHLIGHT)
@@ -0,0 +1,39 @@
+use rui::*;
+
+// An in-window menu bar, for backends without native menus.
+// Press F10 to navigate the menus with the keyboard, or
+// Cmd/Ctrl+Shift+P to search the commands.
+
+fn main() {
+    rui(state(
+        || (false, 0),
+        |s, _| {
+            menu_bar(hstack((
+                circle()
+                    .color(RED_HIGHLIGHT)
+                    .padding(Auto)
+                    .command("File:New", Some(HotKey::KeyN.into()), |_| println!("new"))
+                    .command("File:Save As", "Primary+Shift+S".parse().ok(), |_| {
+                        println!("save as")
+                    }),
+                rectangle()
+                    .corner_radius(5.0)
+                    .color(AZURE_HIGHLIGHT)
+                    .padding(Auto)
+                    .command("Edit:Undo", "Primary+Z".parse().ok(), move |cx| {
+                        cx[s].1 -= 1
+                    })
+                    .title(move |cx| format!("Undo ({})", cx[s].1))
+                    .enabled(move |cx| cx[s].1 > 0)
+                    .command("Edit:Redo", "Primary+Shift+Z".parse().ok(), move |cx| {
+                        cx[s].1 += 1
+                    })
+                    .command("View:Show Grid", "Primary+G".parse().ok(), move |cx| {
+                        cx[s].0 = !cx[s].0
+                    })
+                    .checked(move |cx| cx[s].0),
+            )))
+            .command_palette()
+        },
+    ));
+}
@@ -0,0 +1,26 @@
+use rui::*;
+
+// The popover is presented in the overlay layer, so it isn't
+// clipped by the clipped column it's presented from.
+
+fn main() {
+    rui(state(WorldRect::zero, |anchor, _| {
+        hstack((
+            vstack((
+                button("Show popover", move |cx| {
+                    let popover = text("Hello from the overlay layer")
+                        .padding(Auto)
+                        .background(rectangle().color(BUTTON_BACKGROUND_COLOR));
+                    cx.present_overlay(anchor.id(), cx[anchor], popover);
+                })
+                .geom(move |cx, sz, xform| {
+                    let rect = LocalRect::new(LocalPoint::zero(), sz);
+                    cx[anchor] = xform.outer_transformed_rect(&rect);
+                }),
+                spacer(),
+            ))
+            .clip(),
+            rectangle().color(AZURE_HIGHLIGHT).padding(Auto),
+        ))
+    }));
+}
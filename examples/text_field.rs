@@ -0,0 +1,33 @@
+use rui::*;
+
+#[derive(Default)]
+struct Login {
+    user: String,
+    password: String,
+    pin: String,
+}
+
+make_lens!(LoginUser, Login, String, user);
+make_lens!(LoginPassword, Login, String, password);
+make_lens!(LoginPin, Login, String, pin);
+
+fn main() {
+    rui(state(Login::default, |login, _| {
+        vstack((
+            text_field(bind(login, LoginUser {}))
+                .placeholder("User name")
+                .on_commit(|_| println!("user name committed"))
+                .padding(Auto),
+            text_field(bind(login, LoginPassword {}))
+                .placeholder("Password")
+                .secure()
+                .on_submit(move |cx| println!("logging in as {}", cx[login].user))
+                .padding(Auto),
+            text_field(bind(login, LoginPin {}))
+                .placeholder("PIN")
+                .numeric()
+                .max_length(4)
+                .padding(Auto),
+        ))
+    }));
+}
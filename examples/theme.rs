@@ -0,0 +1,31 @@
+use rui::*;
+
+#[derive(Default)]
+struct Settings {
+    light: bool,
+    volume: f32,
+}
+
+make_lens!(LightLens, Settings, bool, light);
+make_lens!(VolumeLens, Settings, f32, volume);
+
+fn main() {
+    rui(state(Settings::default, |s, cx| {
+        let theme = if cx[s].light {
+            Theme::light()
+        } else {
+            Theme::dark()
+        };
+        vstack((
+            hstack((
+                "Light mode".padding(Auto),
+                toggle(bind(s, LightLens {})).padding(Auto),
+            )),
+            button("Button", |_| println!("clicked")).padding(Auto),
+            hslider(bind(s, VolumeLens {})).padding(Auto),
+            knob(bind(s, VolumeLens {})).padding(Auto),
+        ))
+        .background(rectangle().color(theme.control_background))
+        .env(theme)
+    }));
+}
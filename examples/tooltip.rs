@@ -0,0 +1,10 @@
+use rui::*;
+
+fn main() {
+    rui(hstack((
+        button("Save", |_| println!("saved")).tooltip("Save the document"),
+        button("Delete", |_| println!("deleted"))
+            .tooltip(text("Delete the document").color(RED_HIGHLIGHT))
+            .delay(1.0),
+    )))
+}
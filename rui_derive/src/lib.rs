@@ -0,0 +1,71 @@
+//! Derive macros for rui. Use them through `rui`, which re-exports them.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Generates a lens for each field of a struct, as an associated
+/// function named after the field.
+///
+/// ```ignore
+/// #[derive(Lens)]
+/// struct Mixer {
+///     gain: f32,
+/// }
+///
+/// #[derive(Lens)]
+/// struct App {
+///     mixer: Mixer,
+/// }
+///
+/// let gain = bind(s, App::mixer().then(Mixer::gain()));
+/// ```
+#[proc_macro_derive(Lens)]
+pub fn derive_lens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return unsupported(&input),
+        },
+        _ => return unsupported(&input),
+    };
+
+    let lenses = fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let doc = format!("Lens for the `{}` field.", field_name);
+        quote! {
+            #[doc = #doc]
+            pub fn #field_name() -> impl ::rui::Lens<Self, #ty>
+            where
+                Self: 'static,
+                #ty: 'static,
+            {
+                ::rui::FieldLens::new(
+                    |data: &Self| &data.#field_name,
+                    |data: &mut Self| &mut data.#field_name,
+                )
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#lenses)*
+        }
+    };
+    expanded.into()
+}
+
+fn unsupported(input: &DeriveInput) -> TokenStream {
+    syn::Error::new_spanned(
+        &input.ident,
+        "#[derive(Lens)] only supports structs with named fields",
+    )
+    .to_compile_error()
+    .into()
+}
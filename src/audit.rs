@@ -0,0 +1,257 @@
+use crate::*;
+use accesskit::{NodeBuilder, NodeId, Role};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Default smallest width and height, in points, of views which can be
+/// tapped or dragged. This is the WCAG 2.2 AA target size.
+pub const MIN_HIT_TARGET: f32 = 24.0;
+
+/// Problems found by `Context::audit_accessibility`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccessIssue {
+    /// An interactive view isn't under any accessibility node, so
+    /// assistive technologies can't find it. Give it a `role`.
+    NoNode { view: ViewId },
+
+    /// The node of an interactive view has no name, and no text under it.
+    NoName { node: NodeId },
+
+    /// An interactive view is narrower or shorter than the minimum.
+    SmallHitTarget { view: ViewId, size: LocalSize },
+
+    /// Text doesn't contrast enough with its background to meet WCAG AA.
+    LowContrast { text: String, ratio: f32 },
+
+    /// More than one node has the ID, from a `ViewId` hash collision.
+    DuplicateNodeId { node: NodeId },
+}
+
+impl fmt::Display for AccessIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccessIssue::NoNode { view } => {
+                write!(f, "interactive view {:?} has no accessibility node", view)
+            }
+            AccessIssue::NoName { node } => {
+                write!(f, "interactive node {:?} has no name", node)
+            }
+            AccessIssue::SmallHitTarget { view, size } => write!(
+                f,
+                "hit target {:?} is only {} by {}",
+                view, size.width, size.height
+            ),
+            AccessIssue::LowContrast { text, ratio } => {
+                write!(f, "text {:?} has a contrast ratio of {:.2}", text, ratio)
+            }
+            AccessIssue::DuplicateNodeId { node } => {
+                write!(f, "node ID {:?} is used more than once", node)
+            }
+        }
+    }
+}
+
+/// What views report while an audit gets the accessibility tree.
+pub(crate) struct AuditRecords {
+    /// Interactive views not yet under a node.
+    pub interactive: Vec<ViewId>,
+    /// Interactive views and the nodes they're under.
+    pub placed: Vec<(ViewId, Option<NodeId>)>,
+    /// Fill of the last shape, for finding the color of backgrounds.
+    pub fill: Option<Color>,
+    /// Color text is drawn on.
+    pub background: Color,
+    /// Text with its contrast ratio and font size.
+    pub text: Vec<(String, f32, u32)>,
+}
+
+impl Default for AuditRecords {
+    fn default() -> Self {
+        Self {
+            interactive: vec![],
+            placed: vec![],
+            fill: None,
+            // The window is cleared to black.
+            background: BLACK,
+            text: vec![],
+        }
+    }
+}
+
+/// Blends a color over an opaque one.
+pub(crate) fn blend(color: Color, under: Color) -> Color {
+    let a = color.a;
+    Color {
+        r: color.r * a + under.r * (1.0 - a),
+        g: color.g * a + under.g * (1.0 - a),
+        b: color.b * a + under.b * (1.0 - a),
+        a: 1.0,
+    }
+}
+
+fn luminance(color: Color) -> f32 {
+    let channel = |c: f32| {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+}
+
+/// WCAG contrast ratio between two opaque colors, from 1 to 21.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (la, lb) = (luminance(a), luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// WCAG AA contrast needed for text. Text at least 24 points is large
+/// and needs less.
+pub(crate) fn required_contrast(size: u32) -> f32 {
+    if size >= 24 {
+        3.0
+    } else {
+        4.5
+    }
+}
+
+/// Is the node only there to group others, like the ones stacks make?
+/// Interactive views need a node of their own.
+pub(crate) fn is_container(builder: &NodeBuilder) -> bool {
+    matches!(
+        builder.role(),
+        Role::List | Role::Group | Role::GenericContainer
+    )
+}
+
+/// Does the node have a name, or text under it to take one from?
+pub(crate) fn has_name(nodes: &HashMap<NodeId, &NodeBuilder>, id: NodeId) -> bool {
+    match nodes.get(&id) {
+        Some(builder) => {
+            builder.name().is_some()
+                || builder
+                    .children()
+                    .iter()
+                    .any(|child| has_name(nodes, *child))
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn audit(ui: &impl View) -> Vec<AccessIssue> {
+        let mut cx = Context::new();
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: [200.0, 200.0].into(),
+                cx: &mut cx,
+                text_bounds: &mut |_, size, _| {
+                    LocalRect::new(LocalPoint::zero(), [40.0, size as f32].into())
+                },
+            },
+        );
+        cx.audit_accessibility(ui)
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio(BLACK, Color::WHITE) - 21.0).abs() < 1e-4);
+        assert_eq!(contrast_ratio(BLACK, BLACK), 1.0);
+    }
+
+    #[test]
+    fn test_audit_clean() {
+        let ui = vstack((
+            text("Settings"),
+            button("OK", |_| ()),
+            state(|| 0.5, |v, _| hslider(v).accessibility_label("Volume")),
+        ));
+        assert_eq!(audit(&ui), vec![]);
+    }
+
+    #[test]
+    fn test_audit_interactive() {
+        let issues = audit(&hstack((
+            rectangle().size([40.0, 40.0]).tap(|_| ()),
+            rectangle()
+                .size([40.0, 40.0])
+                .tap(|_| ())
+                .role(Role::Button),
+            rectangle()
+                .size([40.0, 10.0])
+                .tap(|_| ())
+                .role(Role::Button)
+                .accessibility_label("Close"),
+        )));
+
+        assert_eq!(issues.len(), 3);
+        let found = |f: fn(&AccessIssue) -> bool| issues.iter().any(f);
+        assert!(found(|issue| matches!(issue, AccessIssue::NoNode { .. })));
+        assert!(found(|issue| matches!(issue, AccessIssue::NoName { .. })));
+        assert!(found(|issue| matches!(
+            issue,
+            AccessIssue::SmallHitTarget { size, .. } if size.height == 10.0
+        )));
+    }
+
+    #[test]
+    fn test_audit_contrast() {
+        let gray = Color::hex_const("#666666");
+        let issues = audit(&vstack((
+            text("dim").color(gray),
+            text("fine")
+                .color(gray)
+                .padding(5.0)
+                .background(rectangle().color(Color::WHITE)),
+            text("pale")
+                .padding(5.0)
+                .background(rectangle().color(Color::WHITE)),
+            text("large").color(gray).font_size(30),
+        )));
+
+        let low: Vec<_> = issues
+            .iter()
+            .map(|issue| match issue {
+                AccessIssue::LowContrast { text, .. } => text.as_str(),
+                _ => panic!("unexpected {}", issue),
+            })
+            .collect();
+        assert_eq!(low, vec!["dim", "pale"]);
+    }
+
+    /// Gives both copies of its child the same ID, as a hash collision would.
+    struct Twice<V>(V);
+
+    impl<V: View> View for Twice<V> {
+        fn draw(&self, _id: ViewId, _args: &mut DrawArgs) {}
+
+        fn layout(&self, _id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+            args.sz
+        }
+
+        fn access(
+            &self,
+            id: ViewId,
+            cx: &mut Context,
+            nodes: &mut Vec<(NodeId, NodeBuilder)>,
+        ) -> Option<NodeId> {
+            self.0.access(id, cx, nodes);
+            self.0.access(id, cx, nodes)
+        }
+    }
+
+    impl<V> private::Sealed for Twice<V> {}
+
+    #[test]
+    fn test_audit_duplicates() {
+        let issues = audit(&hstack((text("a"), Twice(text("b")))));
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0], AccessIssue::DuplicateNodeId { .. }));
+    }
+}
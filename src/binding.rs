@@ -12,10 +12,30 @@ pub trait Binding<S>: Clone + Copy + 'static {
     fn with_mut<T>(&self, cx: &mut Context, f: impl FnOnce(&mut S) -> T) -> T {
         f(self.get_mut(cx))
     }
+
+    /// Like `get`, but returns `None` instead of panicking when a lens
+    /// finds nothing, such as an index past the end of a `Vec`.
+    fn try_get<'a>(&self, cx: &'a Context) -> Option<&'a S> {
+        Some(self.get(cx))
+    }
+
+    fn try_get_mut<'a>(&self, cx: &'a mut Context) -> Option<&'a mut S> {
+        Some(self.get_mut(cx))
+    }
+
+    fn try_with<T>(&self, cx: &Context, f: impl FnOnce(&S) -> T) -> Option<T> {
+        self.try_get(cx).map(f)
+    }
+
+    fn try_with_mut<T>(&self, cx: &mut Context, f: impl FnOnce(&mut S) -> T) -> Option<T> {
+        self.try_get_mut(cx).map(f)
+    }
 }
 
 pub fn setter<S>(binding: impl Binding<S>) -> impl Fn(S, &mut Context) {
-    move |s, cx| binding.with_mut(cx, |v| *v = s)
+    move |s, cx| {
+        binding.try_with_mut(cx, |v| *v = s);
+    }
 }
 
 pub struct Map<B, L, S, T> {
@@ -84,6 +104,12 @@ where
     fn get_mut<'a>(&self, cx: &'a mut Context) -> &'a mut S {
         self.lens.focus_mut(self.binding.get_mut(cx))
     }
+    fn try_get<'a>(&self, cx: &'a Context) -> Option<&'a S> {
+        self.lens.try_focus(self.binding.try_get(cx)?)
+    }
+    fn try_get_mut<'a>(&self, cx: &'a mut Context) -> Option<&'a mut S> {
+        self.lens.try_focus_mut(self.binding.try_get_mut(cx)?)
+    }
 }
 
 #[cfg(test)]
@@ -5,6 +5,7 @@ use std::any::TypeId;
 use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 use std::ops;
+use std::rc::Rc;
 
 pub type LocalSpace = vger::defs::LocalSpace;
 pub type WorldSpace = vger::defs::WorldSpace;
@@ -20,7 +21,35 @@ pub type WorldToLocal = Transform2D<f32, WorldSpace, LocalSpace>;
 #[derive(Clone, Eq, PartialEq)]
 pub struct CommandInfo {
     pub path: String,
-    pub key: Option<HotKey>,
+    pub key: Option<KeyChord>,
+    /// Title to show in menus.
+    pub title: String,
+    /// Can the command be run?
+    pub enabled: bool,
+    /// Should menus show a check mark?
+    pub checked: bool,
+}
+
+impl CommandInfo {
+    pub fn new(path: &str, key: Option<KeyChord>) -> Self {
+        Self {
+            path: path.into(),
+            key,
+            title: path.rsplit(':').next().unwrap_or_default().into(),
+            enabled: true,
+            checked: false,
+        }
+    }
+}
+
+/// A hotkey bound to more than one command. The first binding wins.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HotKeyConflict {
+    pub key: KeyChord,
+    /// Path of the command the hotkey runs.
+    pub used: String,
+    /// Path of the command whose binding is ignored.
+    pub ignored: String,
 }
 
 pub const DEBUG_LAYOUT: bool = false;
@@ -36,6 +65,43 @@ pub(crate) struct StateHolder {
     pub dirty: bool,
 }
 
+/// Callback registered by `on_appear`, `on_disappear` or `on_change`.
+pub(crate) type LifecycleFn = Rc<dyn Fn(&mut Context)>;
+
+/// Lifecycle callbacks of the views in the tree, in tree order.
+#[derive(Default)]
+pub(crate) struct Lifecycle {
+    pub appear: Vec<(ViewId, LifecycleFn)>,
+    pub disappear: Vec<(ViewId, LifecycleFn)>,
+    /// Compare a value with its snapshot after each event.
+    pub change: Vec<(ViewId, LifecycleFn)>,
+}
+
+/// A view presented above the rest of the UI with `Context::present_overlay`.
+pub(crate) struct Overlay {
+    /// The view which presented the overlay.
+    pub owner: ViewId,
+    /// What the overlay is positioned against, in world space.
+    pub anchor: WorldRect,
+    pub view: Rc<dyn View>,
+    /// Where the overlay was laid out, in world space.
+    pub rect: WorldRect,
+    /// Passive overlays are only drawn: they get no input and aren't
+    /// dismissed by touches outside them or Escape.
+    pub passive: bool,
+}
+
+impl Overlay {
+    /// ID of the root of the overlay's view tree.
+    fn id(&self) -> ViewId {
+        self.owner.child(&"overlay")
+    }
+
+    fn offset(&self) -> LocalOffset {
+        self.rect.origin.to_vector().cast_unit()
+    }
+}
+
 pub(crate) type StateMap = HashMap<ViewId, StateHolder>;
 
 pub(crate) type EnvMap = HashMap<TypeId, Box<dyn Any>>;
@@ -74,6 +140,46 @@ pub struct Context {
     /// The view that has the keyboard focus.
     pub(crate) focused_id: Option<ViewId>,
 
+    /// Focusable views registered with `focus_handle`.
+    pub(crate) focus_handles: HashMap<FocusHandle, ViewId>,
+
+    /// Focus chain of a modal view, which Tab is kept within.
+    pub(crate) focus_trap: Option<Vec<ViewId>>,
+
+    /// Focus request which couldn't be resolved yet.
+    pub(crate) pending_focus: Option<FocusHandle>,
+
+    /// Has the current keyboard event been handled?
+    pub(crate) key_handled: bool,
+
+    /// Has the current keyboard event reached the focused view?
+    pub(crate) key_reached: bool,
+
+    /// Are we processing the subtree of the focused view?
+    pub(crate) in_focused: bool,
+
+    /// Number of focusable views around the view being processed.
+    pub(crate) focus_depth: usize,
+
+    /// Menu commands, recollected when the view tree changes.
+    pub(crate) commands: Vec<CommandInfo>,
+
+    /// Command paths by key chord.
+    pub(crate) hotkeys: HashMap<KeyChord, String>,
+
+    /// Hotkeys bound to more than one command.
+    hotkey_conflicts: Vec<HotKeyConflict>,
+
+    /// Commands to send once the current event is processed.
+    pub(crate) pending_commands: Vec<String>,
+
+    /// Views presented above the UI, from bottom to top.
+    pub(crate) overlays: Vec<Overlay>,
+
+    /// Caret of the focused text view, in world space. Used
+    /// to position the IME candidate window.
+    pub(crate) ime_rect: Option<WorldRect>,
+
     /// The current title of the window
     pub window_title: String,
 
@@ -102,7 +208,7 @@ pub struct Context {
     pub(crate) id_stack: Vec<ViewId>,
 
     /// Previous window size.
-    window_size: Size2D<f32, WorldSpace>,
+    pub(crate) window_size: Size2D<f32, WorldSpace>,
 
     /// Offset for events at the root level.
     root_offset: LocalOffset,
@@ -111,6 +217,66 @@ pub struct Context {
     render_dirty: bool,
 
     pub(crate) access_node_classes: accesskit::NodeClassSet,
+
+    /// Does the window have the keyboard focus? Screen readers are
+    /// only told about the focused view while it does.
+    pub(crate) window_focused: bool,
+
+    /// Node of the focused view, found while getting the accessibility tree.
+    pub(crate) access_focus: Option<accesskit::NodeId>,
+
+    /// Focusable views by their accessibility node, for `Action::Focus`.
+    access_focusables: HashMap<accesskit::NodeId, ViewId>,
+
+    /// Actions handled by views under the accessibility node being built.
+    pub(crate) access_actions: Vec<accesskit::Action>,
+
+    /// Is the view being processed under the node an `Event::Access` targets?
+    pub(crate) in_access_target: bool,
+
+    /// Where views with accessibility nodes are in the window.
+    access_bounds: HashMap<ViewId, WorldRect>,
+
+    /// Is `dirty` visiting the tree to find accessibility bounds rather
+    /// than dirty regions?
+    pub(crate) in_access_bounds_pass: bool,
+
+    /// Window scale factor, as accessibility bounds are in physical pixels.
+    pub(crate) scale_factor: f32,
+
+    /// What views report during `audit_accessibility`.
+    pub(crate) audit: Option<AuditRecords>,
+
+    /// Smallest hit target `audit_accessibility` accepts.
+    min_hit_target: f32,
+
+    /// Accessibility tree as last reported, for finding what changed.
+    access_nodes: HashMap<accesskit::NodeId, accesskit::Node>,
+
+    /// Focus as last reported.
+    access_focus_sent: Option<accesskit::NodeId>,
+
+    /// Accessibility tree changes waiting to be sent to the platform,
+    /// merged into one update.
+    pub(crate) pending_access_update: Option<accesskit::TreeUpdate>,
+
+    /// Where views register lifecycle callbacks during `gc`.
+    pub(crate) lifecycle: Option<Lifecycle>,
+
+    /// Lifecycle callbacks found by the last `gc`.
+    previous_lifecycle: Lifecycle,
+
+    /// Views which were in the tree at the last `gc`.
+    previous_keep: HashSet<ViewId>,
+
+    /// Last seen values of `on_change` bindings.
+    pub(crate) change_snapshots: HashMap<ViewId, Box<dyn Any>>,
+}
+
+/// Accessibility node for the window, which the view tree hangs
+/// from. View IDs are 64 bits, so it can't collide with theirs.
+pub(crate) fn access_root_id() -> accesskit::NodeId {
+    accesskit::NodeId(std::num::NonZeroU128::new(1 << 64).unwrap())
 }
 
 impl Default for Context {
@@ -130,6 +296,19 @@ impl Context {
             key_mods: Default::default(),
             root_id: ViewId { id: 1 },
             focused_id: None,
+            focus_handles: HashMap::new(),
+            focus_trap: None,
+            pending_focus: None,
+            key_handled: false,
+            key_reached: false,
+            in_focused: false,
+            focus_depth: 0,
+            commands: vec![],
+            hotkeys: HashMap::new(),
+            hotkey_conflicts: vec![],
+            pending_commands: vec![],
+            overlays: vec![],
+            ime_rect: None,
             window_title: "rui".into(),
             fullscreen: false,
             state_map: HashMap::new(),
@@ -143,6 +322,23 @@ impl Context {
             root_offset: LocalOffset::zero(),
             render_dirty: false,
             access_node_classes: accesskit::NodeClassSet::default(),
+            window_focused: true,
+            access_focus: None,
+            access_focusables: HashMap::new(),
+            access_actions: vec![],
+            in_access_target: false,
+            access_bounds: HashMap::new(),
+            in_access_bounds_pass: false,
+            scale_factor: 1.0,
+            audit: None,
+            min_hit_target: MIN_HIT_TARGET,
+            access_nodes: HashMap::new(),
+            access_focus_sent: None,
+            pending_access_update: None,
+            lifecycle: None,
+            previous_lifecycle: Lifecycle::default(),
+            change_snapshots: HashMap::new(),
+            previous_keep: HashSet::new(),
         }
     }
 
@@ -151,45 +347,32 @@ impl Context {
         &mut self,
         view: &impl View,
         vger: &mut Vger,
-        access_nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
         window_size: Size2D<f32, WorldSpace>,
     ) -> bool {
         // If the window size has changed, force a relayout.
         if window_size != self.window_size {
             self.deps.clear();
             self.window_size = window_size;
+            self.dirty = true;
         }
 
         // Run any animations.
         let mut actions = vec![];
-        view.process(&Event::Anim, self.root_id, self, &mut actions);
+        self.dispatch(view, &Event::Anim, &mut actions);
+
+        for path in std::mem::take(&mut self.pending_commands) {
+            self.dispatch(view, &Event::Command(path), &mut actions);
+        }
+        self.notify_changes();
 
         if self.dirty {
-            // Clean up state and layout.
-            let mut keep = vec![];
-            view.gc(self.root_id, self, &mut keep);
-            let keep_set = HashSet::<ViewId>::from_iter(keep);
-            self.state_map.retain(|k, _| keep_set.contains(k));
-            self.layout.retain(|k, _| keep_set.contains(k));
-
-            // Get a new accesskit tree.
-            let mut nodes = vec![];
-            view.access(self.root_id, self, &mut nodes);
-
-            if nodes != *access_nodes {
-                println!("access nodes:");
-                for (id, node) in &nodes {
-                    println!(
-                        "  id: {:?} role: {:?}, children: {:?}",
-                        id,
-                        node.role(),
-                        node.children()
-                    );
-                }
-                *access_nodes = nodes;
-            } else {
-                // println!("access nodes unchanged");
-            }
+            let lifecycle_ran = self.gc(view);
+
+            // Refresh focus handles and resolve focus requests.
+            self.update_focus(view);
+
+            // The tree may have changed, so get commands again.
+            self.update_commands(view);
 
             // XXX: we're doing layout both here and in rendering.
             view.layout(
@@ -200,12 +383,26 @@ impl Context {
                     text_bounds: &mut |str, size, max_width| vger.text_bounds(str, size, max_width),
                 },
             );
+            self.layout_overlays(window_size.cast_unit(), &mut |str, size, max_width| {
+                vger.text_bounds(str, size, max_width)
+            });
 
             // Get dirty rectangles.
-            view.dirty(self.root_id, LocalToWorld::identity(), self);
+            self.visit_dirty(view);
+
+            // Get a new accesskit tree, once we know where things are.
+            self.update_access_bounds(view);
+            if let Some(update) = self.access_update(view) {
+                self.queue_access_update(update);
+            }
 
             self.clear_dirty();
 
+            // Callbacks may have changed the tree, so look again.
+            if lifecycle_ran {
+                self.dirty = true;
+            }
+
             true
         } else {
             false
@@ -249,11 +446,28 @@ impl Context {
             },
         );
 
+        self.layout_overlays(local_window_size, &mut |str, size, max_width| {
+            vger.text_bounds(str, size, max_width)
+        });
+
         // Center the root view in the window.
         self.root_offset = ((local_window_size - sz) / 2.0).into();
 
+        vger.save();
         vger.translate(self.root_offset);
+        self.ime_rect = None;
         view.draw(self.root_id, &mut DrawArgs { cx: self, vger });
+        vger.restore();
+
+        // Overlays are drawn on top, in world space.
+        for i in 0..self.overlays.len() {
+            let overlay = &self.overlays[i];
+            let (id, view, offset) = (overlay.id(), overlay.view.clone(), overlay.offset());
+            vger.save();
+            vger.translate(offset);
+            view.draw(id, &mut DrawArgs { cx: self, vger });
+            vger.restore();
+        }
         self.enable_dirty = true;
 
         if self.render_dirty {
@@ -289,7 +503,7 @@ impl Context {
             depth_stencil_attachment: None,
         };
 
-        vger.encode(device, &desc, render_info.queue);
+        vger.shim_encode(device, &desc, render_info.queue);
 
         frame.present();
     }
@@ -297,12 +511,47 @@ impl Context {
     /// Process a UI event.
     pub fn process(&mut self, view: &impl View, event: &Event) {
         let mut actions = vec![];
-        view.process(
-            &event.offset(-self.root_offset),
-            self.root_id,
-            self,
-            &mut actions,
-        );
+        self.key_handled = false;
+        self.key_reached = false;
+
+        if let Event::Access(request) = event {
+            if request.action == accesskit::Action::Focus {
+                if let Some(id) = self.access_focusables.get(&request.target) {
+                    self.focused_id = Some(*id);
+                    self.set_dirty();
+                }
+            }
+        }
+
+        self.dispatch(view, event, &mut actions);
+
+        if event.is_keyboard()
+            && self.focused_id.is_some()
+            && !self.key_reached
+            && !self.key_handled
+        {
+            // The focused view is gone, so deliver from the root instead.
+            self.focused_id = None;
+            self.key_handled = false;
+            self.dispatch(view, event, &mut actions);
+        }
+
+        if let Event::KeyDown { key, mods, repeat } = event {
+            if !self.key_handled {
+                if *key == Key::Tab {
+                    self.move_focus(view, mods.shift);
+                } else if let Some(path) = self.hotkey_command(key, mods) {
+                    if !repeat {
+                        self.pending_commands.push(path);
+                    }
+                }
+            }
+        }
+
+        for path in std::mem::take(&mut self.pending_commands) {
+            self.dispatch(view, &Event::Command(path), &mut actions);
+        }
+        self.notify_changes();
 
         for action in actions {
             if !action.is::<()>() {
@@ -311,11 +560,706 @@ impl Context {
         }
     }
 
+    /// Sends an event to the overlays, then to the view unless an
+    /// overlay consumed it.
+    fn dispatch(&mut self, view: &impl View, event: &Event, actions: &mut Vec<Box<dyn Any>>) {
+        if !self.process_overlays(event, actions) {
+            view.process(
+                &event.offset(-self.root_offset),
+                self.root_id,
+                self,
+                actions,
+            );
+        }
+    }
+
+    /// Gives overlays the first chance at an event, returning true if
+    /// the event shouldn't go to the rest of the UI.
+    ///
+    /// Touches go to the topmost overlay under them. A touch outside all
+    /// overlays dismisses the topmost one, as does an unhandled Escape.
+    fn process_overlays(&mut self, event: &Event, actions: &mut Vec<Box<dyn Any>>) -> bool {
+        let topmost = |overlays: &Vec<Overlay>| overlays.iter().rposition(|o| !o.passive);
+        if topmost(&self.overlays).is_none() {
+            return false;
+        }
+
+        if let Event::TouchBegin { position, .. } = event {
+            let pt = position.cast_unit();
+            match self
+                .overlays
+                .iter()
+                .rposition(|o| !o.passive && o.rect.contains(pt))
+            {
+                Some(i) => {
+                    let overlay = &self.overlays[i];
+                    let (id, view, offset) = (overlay.id(), overlay.view.clone(), overlay.offset());
+                    view.process(&event.offset(-offset), id, self, actions);
+                }
+                None => {
+                    if let Some(i) = topmost(&self.overlays) {
+                        self.overlays.remove(i);
+                    }
+                    self.set_dirty();
+                }
+            }
+            return true;
+        }
+
+        // Topmost first, in case one is removed along the way.
+        for i in (0..self.overlays.len()).rev() {
+            if let Some(overlay) = self.overlays.get(i).filter(|o| !o.passive) {
+                let (id, view, offset) = (overlay.id(), overlay.view.clone(), overlay.offset());
+                view.process(&event.offset(-offset), id, self, actions);
+            }
+        }
+
+        if let Event::KeyDown {
+            key: Key::Escape, ..
+        } = event
+        {
+            if !self.key_handled {
+                if let Some(i) = topmost(&self.overlays) {
+                    self.overlays.remove(i);
+                    self.key_handled = true;
+                    self.set_dirty();
+                }
+            }
+        }
+
+        event.is_keyboard() && self.key_handled
+    }
+
+    /// Lays out overlays and positions them against their anchors: below
+    /// if there's room, otherwise above, and inside the window.
+    pub(crate) fn layout_overlays(
+        &mut self,
+        window_size: LocalSize,
+        text_bounds: &mut dyn FnMut(&str, u32, Option<f32>) -> LocalRect,
+    ) {
+        for (i, (id, view)) in self.overlay_views().into_iter().enumerate() {
+            let sz = view.layout(
+                id,
+                &mut LayoutArgs {
+                    sz: window_size,
+                    cx: self,
+                    text_bounds,
+                },
+            );
+
+            let anchor = self.overlays[i].anchor;
+            let x = anchor.min_x().min(window_size.width - sz.width).max(0.0);
+            let y = if anchor.min_y() >= sz.height {
+                anchor.min_y() - sz.height
+            } else {
+                anchor.max_y().min(window_size.height - sz.height).max(0.0)
+            };
+            self.overlays[i].rect = WorldRect::new([x, y].into(), sz.cast_unit());
+        }
+    }
+
+    fn overlay_views(&self) -> Vec<(ViewId, Rc<dyn View>)> {
+        self.overlays
+            .iter()
+            .map(|overlay| (overlay.id(), overlay.view.clone()))
+            .collect()
+    }
+
+    /// Presents a view above the rest of the UI, so it isn't clipped or
+    /// covered by other views. It's placed below `anchor`, or above if
+    /// there's no room. The anchor is in world space, as given by `geom`.
+    ///
+    /// `id` identifies the overlay. Presenting again with the same `id`
+    /// replaces it.
+    pub fn present_overlay(&mut self, id: ViewId, anchor: WorldRect, view: impl View) {
+        self.insert_overlay(id, anchor, Rc::new(view), false)
+    }
+
+    /// Presents an overlay which doesn't take input, like a tooltip.
+    pub(crate) fn present_passive_overlay(
+        &mut self,
+        id: ViewId,
+        anchor: WorldRect,
+        view: impl View,
+    ) {
+        self.insert_overlay(id, anchor, Rc::new(view), true)
+    }
+
+    fn insert_overlay(&mut self, id: ViewId, anchor: WorldRect, view: Rc<dyn View>, passive: bool) {
+        let overlay = Overlay {
+            owner: id,
+            anchor,
+            view,
+            rect: WorldRect::zero(),
+            passive,
+        };
+        match self.overlays.iter_mut().find(|o| o.owner == id) {
+            Some(o) => *o = overlay,
+            None => self.overlays.push(overlay),
+        }
+
+        // The overlay needs layout.
+        self.dirty = true;
+    }
+
+    /// Removes the overlay presented with `id`.
+    pub fn dismiss_overlay(&mut self, id: ViewId) {
+        self.overlays.retain(|o| o.owner != id);
+        self.set_dirty();
+    }
+
+    /// Is the overlay presented with `id` showing?
+    pub fn is_overlay_presented(&self, id: ViewId) -> bool {
+        self.overlays.iter().any(|o| o.owner == id)
+    }
+
+    /// Gets the accessibility tree and returns what changed since the
+    /// last call, or `None` if nothing did. The first update has the
+    /// whole tree.
+    pub fn access_update(&mut self, view: &impl View) -> Option<accesskit::TreeUpdate> {
+        let mut builders = vec![];
+        self.access_focus = None;
+        self.access_focusables.clear();
+        self.access_actions.clear();
+        let mut children: Vec<_> = view
+            .access(self.root_id, self, &mut builders)
+            .into_iter()
+            .collect();
+        for (id, overlay) in self.overlay_views() {
+            children.extend(overlay.access(id, self, &mut builders));
+        }
+
+        let root_id = access_root_id();
+        let mut root = accesskit::NodeBuilder::new(accesskit::Role::Window);
+        root.set_name(self.window_title.as_str());
+        root.set_bounds(accesskit::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: self.window_size.width as f64,
+            y1: self.window_size.height as f64,
+        });
+        root.set_transform(accesskit::Affine::scale(self.scale_factor as f64));
+        root.set_children(children);
+        builders.push((root_id, root));
+
+        let nodes: HashMap<_, _> = builders
+            .into_iter()
+            .map(|(id, builder)| (id, builder.build(&mut self.access_node_classes)))
+            .collect();
+        let focus = if self.window_focused {
+            Some(self.access_focus.unwrap_or(root_id))
+        } else {
+            None
+        };
+
+        // Nodes which are no longer referenced are removed, so we
+        // only need to send new and changed ones.
+        let initial = self.access_nodes.is_empty();
+        let changed: Vec<_> = nodes
+            .iter()
+            .filter(|(id, node)| self.access_nodes.get(id) != Some(node))
+            .map(|(id, node)| (*id, node.clone()))
+            .collect();
+
+        if !initial && changed.is_empty() && focus == self.access_focus_sent {
+            return None;
+        }
+
+        self.access_nodes = nodes;
+        self.access_focus_sent = focus;
+        Some(accesskit::TreeUpdate {
+            nodes: changed,
+            tree: initial.then(|| accesskit::Tree::new(root_id)),
+            focus,
+        })
+    }
+
+    /// Merges an update into the one waiting to be sent, so updates
+    /// pile up no further than the size of the tree if nobody sends them.
+    pub(crate) fn queue_access_update(&mut self, update: accesskit::TreeUpdate) {
+        let pending = match &mut self.pending_access_update {
+            Some(pending) => pending,
+            None => {
+                self.pending_access_update = Some(update);
+                return;
+            }
+        };
+        for (id, node) in update.nodes {
+            match pending.nodes.iter_mut().find(|(old, _)| *old == id) {
+                Some(entry) => entry.1 = node,
+                None => pending.nodes.push((id, node)),
+            }
+        }
+        if update.tree.is_some() {
+            pending.tree = update.tree;
+        }
+        pending.focus = update.focus;
+    }
+
+    /// Starts the accessibility node for a view, placed where `dirty`
+    /// last found it.
+    pub(crate) fn access_node(&self, id: ViewId, role: accesskit::Role) -> accesskit::NodeBuilder {
+        let mut builder = accesskit::NodeBuilder::new(role);
+        if let Some(rect) = self.access_bounds.get(&id) {
+            // Our world space is y-up, but AccessKit's is y-down.
+            let height = self.window_size.height as f64;
+            builder.set_bounds(accesskit::Rect {
+                x0: rect.min_x() as f64,
+                y0: height - rect.max_y() as f64,
+                x1: rect.max_x() as f64,
+                y1: height - rect.min_y() as f64,
+            });
+        }
+        builder
+    }
+
+    /// Calls `dirty` on the view and the overlays.
+    fn visit_dirty(&mut self, view: &impl View) {
+        let root_xform = LocalToWorld::translation(self.root_offset.x, self.root_offset.y);
+        view.dirty(self.root_id, root_xform, self);
+        for i in 0..self.overlays.len() {
+            let overlay = &self.overlays[i];
+            let (id, view) = (overlay.id(), overlay.view.clone());
+            let xform = LocalToWorld::translation(overlay.rect.min_x(), overlay.rect.min_y());
+            view.dirty(id, xform, self);
+        }
+    }
+
+    /// Finds where views with accessibility nodes are. This visits the
+    /// whole tree, whereas finding dirty regions stops at dirty state.
+    pub(crate) fn update_access_bounds(&mut self, view: &impl View) {
+        self.in_access_bounds_pass = true;
+        self.visit_dirty(view);
+        self.in_access_bounds_pass = false;
+    }
+
+    /// Records where a view with an accessibility node is, from its
+    /// layout box and the transform passed to `dirty`, during
+    /// `update_access_bounds`.
+    pub(crate) fn set_access_bounds(&mut self, id: ViewId, xform: LocalToWorld) {
+        if !self.in_access_bounds_pass {
+            return;
+        }
+        if let Some(layout) = self.layout.get(&id) {
+            let rect = xform.outer_transformed_rect(&layout.rect);
+            self.access_bounds.insert(id, rect);
+        }
+    }
+
+    /// Records the accessibility node of a focusable view, so it can
+    /// be reported as focused and focused by `Action::Focus`.
+    pub(crate) fn access_focusable(
+        &mut self,
+        id: ViewId,
+        aid: Option<accesskit::NodeId>,
+        nodes: &mut [(accesskit::NodeId, accesskit::NodeBuilder)],
+    ) -> Option<accesskit::NodeId> {
+        if self.focused_id == Some(id) {
+            self.access_focus = aid;
+        }
+        if let Some(aid) = aid {
+            self.access_focusables.insert(aid, id);
+            if let Some((_, builder)) = nodes.iter_mut().find(|(node_id, _)| *node_id == aid) {
+                builder.add_action(accesskit::Action::Focus);
+            }
+        }
+        aid
+    }
+
+    /// Gets accessibility for the children of a view with its own node,
+    /// returning the actions handled by views between it and the next nodes.
+    pub(crate) fn access_children<R>(
+        &mut self,
+        id: ViewId,
+        f: impl FnOnce(&mut Context) -> R,
+    ) -> (R, Vec<accesskit::Action>) {
+        let outer = std::mem::take(&mut self.access_actions);
+        let outer_interactive = self
+            .audit
+            .as_mut()
+            .map(|audit| std::mem::take(&mut audit.interactive));
+        let r = f(self);
+        if let (Some(audit), Some(outer)) = (&mut self.audit, outer_interactive) {
+            let inner = std::mem::replace(&mut audit.interactive, outer);
+            let aid = id.access_id();
+            audit
+                .placed
+                .extend(inner.into_iter().map(|view| (view, Some(aid))));
+        }
+        (r, std::mem::replace(&mut self.access_actions, outer))
+    }
+
+    /// Checks the view for common accessibility problems: interactive views
+    /// which assistive technologies can't find or name, small hit targets,
+    /// low contrast text and duplicate node IDs. Lay the view out first,
+    /// as `update` does.
+    pub fn audit_accessibility(&mut self, view: &impl View) -> Vec<AccessIssue> {
+        self.audit = Some(AuditRecords::default());
+        self.access_actions.clear();
+        let mut builders = vec![];
+        view.access(self.root_id, self, &mut builders);
+        for (id, overlay) in self.overlay_views() {
+            overlay.access(id, self, &mut builders);
+        }
+        let mut records = self.audit.take().unwrap();
+        let unplaced = std::mem::take(&mut records.interactive);
+        records
+            .placed
+            .extend(unplaced.into_iter().map(|view| (view, None)));
+
+        let mut issues = vec![];
+        let mut nodes = HashMap::new();
+        for (aid, builder) in &builders {
+            if nodes.insert(*aid, builder).is_some() {
+                issues.push(AccessIssue::DuplicateNodeId { node: *aid });
+            }
+        }
+
+        let mut unnamed = HashSet::new();
+        for (view, aid) in records.placed {
+            // Nodes like the ones stacks make only group their children.
+            let aid = aid.filter(|aid| nodes.get(aid).is_some_and(|b| !is_container(b)));
+            match aid {
+                None => issues.push(AccessIssue::NoNode { view }),
+                Some(aid) => {
+                    if !has_name(&nodes, aid) && unnamed.insert(aid) {
+                        issues.push(AccessIssue::NoName { node: aid });
+                    }
+                }
+            }
+            if let Some(layout) = self.layout.get(&view) {
+                let size = layout.rect.size;
+                if size.width < self.min_hit_target || size.height < self.min_hit_target {
+                    issues.push(AccessIssue::SmallHitTarget { view, size });
+                }
+            }
+        }
+
+        for (text, ratio, size) in records.text {
+            if ratio < required_contrast(size) {
+                issues.push(AccessIssue::LowContrast { text, ratio });
+            }
+        }
+
+        issues
+    }
+
+    /// Sets the smallest width and height `audit_accessibility` accepts
+    /// for views which can be tapped or dragged.
+    pub fn set_min_hit_target(&mut self, size: f32) {
+        self.min_hit_target = size;
+    }
+
+    /// Notes an interactive view while auditing, so we can check it
+    /// ends up under a named node.
+    pub(crate) fn audit_interactive(&mut self, id: ViewId) {
+        if let Some(audit) = &mut self.audit {
+            audit.interactive.push(id);
+        }
+    }
+
+    /// Notes the color of a shape while auditing, for backgrounds.
+    pub(crate) fn audit_fill(&mut self, color: Color) {
+        if let Some(audit) = &mut self.audit {
+            audit.fill = Some(color);
+        }
+    }
+
+    /// Notes the contrast of text against its background while auditing.
+    pub(crate) fn audit_text(&mut self, text: &str, color: Color, size: u32) {
+        if let Some(audit) = &mut self.audit {
+            let ratio = contrast_ratio(blend(color, audit.background), audit.background);
+            audit.text.push((text.into(), ratio, size));
+        }
+    }
+
+    /// Gets accessibility for a view drawn over a background. While
+    /// auditing, text is checked against the background's color.
+    pub(crate) fn access_over<R>(
+        &mut self,
+        id: ViewId,
+        background: &impl View,
+        f: impl FnOnce(&mut Context) -> R,
+    ) -> R {
+        if self.audit.is_none() {
+            return f(self);
+        }
+        background.access(id, self, &mut vec![]);
+        let audit = self.audit.as_mut().unwrap();
+        let outer = audit.background;
+        if let Some(fill) = audit.fill.take() {
+            audit.background = blend(fill, outer);
+        }
+        let r = f(self);
+        if let Some(audit) = &mut self.audit {
+            audit.background = outer;
+        }
+        r
+    }
+
+    /// Processes the children of a view with its own accessibility node,
+    /// noting whether an `Event::Access` is aimed at the node.
+    pub(crate) fn process_access_node(
+        &mut self,
+        id: ViewId,
+        event: &Event,
+        f: impl FnOnce(&mut Context),
+    ) {
+        if let Event::Access(request) = event {
+            let outer = self.in_access_target;
+            self.in_access_target = request.target == id.access_id();
+            f(self);
+            self.in_access_target = outer;
+        } else {
+            f(self)
+        }
+    }
+
+    /// The accessibility action aimed at the node of the view being
+    /// processed, if any.
+    pub(crate) fn access_request<'a>(
+        &self,
+        event: &'a Event,
+    ) -> Option<&'a accesskit::ActionRequest> {
+        match event {
+            Event::Access(request) if self.in_access_target => Some(request),
+            _ => None,
+        }
+    }
+
     /// Get menu commands.
     pub fn commands(&mut self, view: &impl View, cmds: &mut Vec<CommandInfo>) {
         view.commands(self.root_id, self, cmds);
     }
 
+    /// Sends `Event::Command` for a command path from the root of
+    /// the tree, after the current event has been processed.
+    pub fn run_command(&mut self, path: &str) {
+        self.pending_commands.push(path.into());
+    }
+
+    /// Hotkeys bound to more than one command, as of the last time
+    /// commands were collected.
+    pub fn hotkey_conflicts(&self) -> &[HotKeyConflict] {
+        &self.hotkey_conflicts
+    }
+
+    /// Recollects commands and their hotkeys, recording conflicting bindings.
+    pub(crate) fn update_commands(&mut self, view: &impl View) {
+        let mut cmds = vec![];
+        self.commands(view, &mut cmds);
+        if cmds == self.commands {
+            return;
+        }
+
+        // Titles may have changed size, so menus need layout.
+        self.deps.clear();
+
+        self.hotkeys.clear();
+        self.hotkey_conflicts.clear();
+        for cmd in &cmds {
+            if let Some(key) = cmd.key {
+                match self.hotkeys.get(&key) {
+                    Some(path) if *path != cmd.path => self.hotkey_conflicts.push(HotKeyConflict {
+                        key,
+                        used: path.clone(),
+                        ignored: cmd.path.clone(),
+                    }),
+                    Some(_) => (),
+                    None => {
+                        self.hotkeys.insert(key, cmd.path.clone());
+                    }
+                }
+            }
+        }
+        self.commands = cmds;
+    }
+
+    /// Finds the command triggered by a key press, if any.
+    fn hotkey_command(&self, key: &Key, mods: &KeyboardModifiers) -> Option<String> {
+        self.hotkeys.get(&KeyChord::new(*key, *mods)).cloned()
+    }
+
+    /// Moves keyboard focus to the view registered with `handle`.
+    pub fn focus(&mut self, handle: FocusHandle) {
+        if let Some(id) = self.focus_handles.get(&handle) {
+            if self.focused_id != Some(*id) {
+                self.focused_id = Some(*id);
+                self.set_dirty();
+            }
+        } else {
+            // The view hasn't been seen yet, so try again after the next update.
+            self.pending_focus = Some(handle);
+            self.dirty = true;
+        }
+    }
+
+    /// Removes keyboard focus from whichever view has it.
+    pub fn clear_focus(&mut self) {
+        if self.focused_id.is_some() {
+            self.focused_id = None;
+            self.set_dirty();
+        }
+    }
+
+    /// Stops the current keyboard event from bubbling up to ancestors
+    /// of the view handling it.
+    pub fn set_handled(&mut self) {
+        self.key_handled = true;
+    }
+
+    /// Processes the children of a focusable view, tracking whether
+    /// we're inside the focused subtree.
+    pub(crate) fn process_focusable(&mut self, id: ViewId, f: impl FnOnce(&mut Context)) {
+        let in_focused = self.in_focused;
+        if self.focused_id == Some(id) {
+            self.in_focused = true;
+            self.key_reached = true;
+        }
+        self.focus_depth += 1;
+        f(self);
+        self.focus_depth -= 1;
+        self.in_focused = in_focused;
+    }
+
+    /// Should a keyboard handler receive the current event?
+    ///
+    /// Events go first to the focused view and its subtree, then bubble
+    /// up through its ancestors. `reached` is whether the event had reached
+    /// the focused view before the handler's children were processed. With
+    /// nothing focused, only handlers outside focusable views get the event.
+    pub(crate) fn is_key_target(&self, reached: bool) -> bool {
+        if self.key_handled {
+            false
+        } else if self.focused_id.is_some() {
+            self.in_focused || (!reached && self.key_reached)
+        } else {
+            self.focus_depth == 0
+        }
+    }
+
+    /// Cleans up state and layout of views no longer in the tree, then
+    /// calls `on_disappear` for views which left the tree and `on_appear`
+    /// for views which joined it, each in tree order. Returns whether
+    /// any callbacks were called.
+    pub(crate) fn gc(&mut self, view: &impl View) -> bool {
+        let mut keep = vec![];
+        self.lifecycle = Some(Lifecycle::default());
+        view.gc(self.root_id, self, &mut keep);
+        for (id, view) in self.overlay_views() {
+            view.gc(id, self, &mut keep);
+        }
+        let lifecycle = self.lifecycle.take().unwrap_or_default();
+        let keep_set = HashSet::<ViewId>::from_iter(keep);
+        self.state_map.retain(|k, _| keep_set.contains(k));
+        self.layout.retain(|k, _| keep_set.contains(k));
+        self.access_bounds.retain(|k, _| keep_set.contains(k));
+        self.change_snapshots.retain(|k, _| keep_set.contains(k));
+
+        let disappeared: Vec<_> = self
+            .previous_lifecycle
+            .disappear
+            .iter()
+            .filter(|(id, _)| !keep_set.contains(id))
+            .map(|(_, f)| f.clone())
+            .collect();
+        let appeared: Vec<_> = lifecycle
+            .appear
+            .iter()
+            .filter(|(id, _)| !self.previous_keep.contains(id))
+            .map(|(_, f)| f.clone())
+            .collect();
+
+        self.previous_lifecycle = lifecycle;
+        self.previous_keep = keep_set;
+
+        for f in disappeared.iter().chain(&appeared) {
+            f(self);
+        }
+
+        !disappeared.is_empty() || !appeared.is_empty()
+    }
+
+    /// Calls `on_change` closures, in tree order, for bindings whose
+    /// values changed since they were last seen.
+    fn notify_changes(&mut self) {
+        let observers: Vec<_> = self
+            .previous_lifecycle
+            .change
+            .iter()
+            .map(|(_, f)| f.clone())
+            .collect();
+        for f in observers {
+            f(self);
+        }
+    }
+
+    /// Gets the focus chain, updating focus handles along the way.
+    pub(crate) fn update_focus(&mut self, view: &impl View) -> Vec<ViewId> {
+        let mut chain = vec![];
+        self.focus_handles.clear();
+        self.focus_trap = None;
+        view.focus_chain(self.root_id, self, &mut chain);
+        for (id, view) in self.overlay_views() {
+            view.focus_chain(id, self, &mut chain);
+        }
+
+        // A modal view only allows focus within itself.
+        if let Some(trap) = self.focus_trap.take() {
+            if let Some(id) = self.focused_id {
+                if !trap.contains(&id) {
+                    self.focused_id = None;
+                }
+            }
+            chain = trap;
+        }
+
+        if let Some(handle) = self.pending_focus.take() {
+            if let Some(id) = self.focus_handles.get(&handle) {
+                self.focused_id = Some(*id);
+            }
+        }
+
+        chain
+    }
+
+    /// Moves focus to the next (or previous) view in the focus chain.
+    fn move_focus(&mut self, view: &impl View, reverse: bool) {
+        let chain = self.update_focus(view);
+        if chain.is_empty() {
+            return;
+        }
+
+        let n = chain.len();
+        let next = match self
+            .focused_id
+            .and_then(|id| chain.iter().position(|x| *x == id))
+        {
+            Some(i) if reverse => (i + n - 1) % n,
+            Some(i) => (i + 1) % n,
+            None if reverse => n - 1,
+            None => 0,
+        };
+
+        self.focused_id = Some(chain[next]);
+        self.set_dirty();
+    }
+
+    /// The caret of the focused text view, if any, for
+    /// positioning the IME candidate window.
+    pub fn ime_rect(&self) -> Option<WorldRect> {
+        self.ime_rect
+    }
+
+    /// The rect a view was given by its last layout, for views which
+    /// store it, like shapes and custom views.
+    pub fn layout_rect(&self, id: ViewId) -> LocalRect {
+        self.layout
+            .get(&id)
+            .map(|layout| layout.rect)
+            .unwrap_or_default()
+    }
+
     pub(crate) fn set_dirty(&mut self) {
         if self.enable_dirty {
             self.dirty = true
@@ -369,6 +1313,27 @@ impl Context {
         old_value
     }
 
+    /// Puts back the value `set_env` replaced, or removes it if there wasn't one.
+    pub(crate) fn restore_env<S: Clone + 'static>(&mut self, old_value: Option<S>) {
+        match old_value {
+            Some(value) => {
+                self.set_env(&value);
+            }
+            None => {
+                self.env.remove(&TypeId::of::<S>());
+            }
+        }
+    }
+
+    /// The theme for the view being processed, set with `.env(theme)`.
+    pub fn theme(&self) -> Theme {
+        self.env
+            .get(&TypeId::of::<Theme>())
+            .and_then(|b| b.downcast_ref::<Theme>())
+            .copied()
+            .unwrap_or_default()
+    }
+
     pub fn get<S>(&self, id: StateHandle<S>) -> &S
     where
         S: 'static,
@@ -407,3 +1372,153 @@ where
         self.get_mut(index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::Cell;
+
+    fn touch(cx: &mut Context, ui: &impl View, position: LocalPoint) {
+        cx.process(ui, &Event::TouchBegin { id: 0, position });
+        cx.process(ui, &Event::TouchEnd { id: 0, position });
+    }
+
+    #[test]
+    fn test_overlay() {
+        let mut cx = Context::new();
+        let taps = Rc::new(Cell::new(0));
+        let overlay_taps = Rc::new(Cell::new(0));
+
+        let ui = {
+            let taps = taps.clone();
+            rectangle().tap(move |_| taps.set(taps.get() + 1))
+        };
+        let text_bounds = &mut |_: &str, _, _| LocalRect::zero();
+        let window_size = LocalSize::new(200.0, 200.0);
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: window_size,
+                cx: &mut cx,
+                text_bounds,
+            },
+        );
+
+        let owner = ViewId { id: 42 };
+        let popup = {
+            let overlay_taps = overlay_taps.clone();
+            rectangle()
+                .tap(move |_| overlay_taps.set(overlay_taps.get() + 1))
+                .size([50.0, 20.0])
+        };
+        let anchor = WorldRect::new([50.0, 100.0].into(), [50.0, 20.0].into());
+        cx.present_overlay(owner, anchor, popup);
+        cx.layout_overlays(window_size, text_bounds);
+
+        // Placed below the anchor.
+        assert_eq!(
+            cx.overlays[0].rect,
+            WorldRect::new([50.0, 80.0].into(), [50.0, 20.0].into())
+        );
+
+        // The overlay gets touches over it, and the view underneath doesn't.
+        touch(&mut cx, &ui, [60.0, 90.0].into());
+        assert_eq!((overlay_taps.get(), taps.get()), (1, 0));
+
+        // Touching outside dismisses it.
+        touch(&mut cx, &ui, [10.0, 10.0].into());
+        assert!(!cx.is_overlay_presented(owner));
+        assert_eq!(taps.get(), 0);
+        touch(&mut cx, &ui, [10.0, 10.0].into());
+        assert_eq!(taps.get(), 1);
+
+        // No room below, so it goes above.
+        let anchor = WorldRect::new([190.0, 5.0].into(), [10.0, 10.0].into());
+        cx.present_overlay(owner, anchor, rectangle().size([50.0, 20.0]));
+        cx.layout_overlays(window_size, text_bounds);
+        assert_eq!(
+            cx.overlays[0].rect,
+            WorldRect::new([150.0, 15.0].into(), [50.0, 20.0].into())
+        );
+
+        cx.process(
+            &ui,
+            &Event::KeyDown {
+                key: Key::Escape,
+                mods: Default::default(),
+                repeat: false,
+            },
+        );
+        assert!(!cx.is_overlay_presented(owner));
+    }
+
+    #[test]
+    fn test_access_update() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, cx| {
+                vstack((
+                    format!("{}", cx[count]),
+                    button("increment", move |cx| cx[count] += 1),
+                ))
+            },
+        );
+        let count = StateHandle::<i32>::new(cx.root_id);
+
+        // The first update has the whole tree, hung from the window.
+        let update = cx.access_update(&ui).unwrap();
+        let root = access_root_id();
+        assert_eq!(update.tree.map(|tree| tree.root), Some(root));
+        let (_, window) = update.nodes.iter().find(|(id, _)| *id == root).unwrap();
+        assert_eq!(window.role(), accesskit::Role::Window);
+        assert_eq!(update.focus, Some(root));
+
+        assert!(cx.access_update(&ui).is_none());
+
+        // Only changed nodes are sent.
+        cx[count] = 1;
+        let update = cx.access_update(&ui).unwrap();
+        assert!(update.tree.is_none());
+        assert_eq!(update.nodes.len(), 1);
+        assert_eq!(update.nodes[0].1.name(), Some("1"));
+
+        // Focus moves to the button's node.
+        let chain = cx.update_focus(&ui);
+        cx.focused_id = Some(chain[0]);
+        let update = cx.access_update(&ui).unwrap();
+        assert!(update.nodes.is_empty());
+        let button = update.focus.unwrap();
+        assert_eq!(cx.access_nodes[&button].role(), accesskit::Role::Button);
+
+        // No focus while the window doesn't have it.
+        cx.window_focused = false;
+        assert_eq!(cx.access_update(&ui).unwrap().focus, None);
+    }
+
+    #[test]
+    fn test_queue_access_update() {
+        let mut cx = Context::new();
+        let ui = state(|| 0, |count, cx| text(&format!("{}", cx[count])));
+        let count = StateHandle::<i32>::new(cx.root_id);
+
+        let update = cx.access_update(&ui).unwrap();
+        let len = update.nodes.len();
+        cx.queue_access_update(update);
+
+        // Unsent updates are merged, keeping the latest nodes.
+        for i in 1..10 {
+            cx[count] = i;
+            let update = cx.access_update(&ui).unwrap();
+            cx.queue_access_update(update);
+        }
+        let pending = cx.pending_access_update.take().unwrap();
+        assert_eq!(pending.nodes.len(), len);
+        assert!(pending.tree.is_some());
+        assert!(pending
+            .nodes
+            .iter()
+            .any(|(_, node)| node.name() == Some("9")));
+    }
+}
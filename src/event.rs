@@ -27,11 +27,43 @@ pub enum Event {
     /// Menu command.
     Command(String),
 
-    /// Key press.
-    Key(Key),
+    /// Key pressed, or auto-repeated while held down.
+    KeyDown {
+        key: Key,
+        /// Modifiers held when the key was pressed.
+        mods: KeyboardModifiers,
+        /// Is this an auto-repeat of a key that's already down?
+        repeat: bool,
+    },
+
+    /// Key released.
+    KeyUp {
+        key: Key,
+        /// Modifiers held when the key was released.
+        mods: KeyboardModifiers,
+    },
+
+    /// Text typed on the keyboard, after the keyboard layout
+    /// and any dead keys have been applied.
+    TextInput(String),
+
+    /// Text being composed by an input method (IME). An empty
+    /// string clears the composition.
+    ImePreedit {
+        text: String,
+        /// Byte range of the IME's cursor within `text`, if shown.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// Text committed by an input method (IME).
+    ImeCommit(String),
 
     /// Animation.
     Anim,
+
+    /// Action requested by an assistive technology, such as a screen
+    /// reader. Goes to the views under the target's node.
+    Access(accesskit::ActionRequest),
 }
 
 impl Event {
@@ -45,6 +77,18 @@ impl Event {
         }
         event
     }
+
+    /// Is this an event which is routed to the focused view?
+    pub fn is_keyboard(&self) -> bool {
+        matches!(
+            self,
+            Event::KeyDown { .. }
+                | Event::KeyUp { .. }
+                | Event::TextInput(_)
+                | Event::ImePreedit { .. }
+                | Event::ImeCommit(_)
+        )
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -54,7 +98,7 @@ pub enum MouseButton {
     Center,
 }
 
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub struct KeyboardModifiers {
     pub shift: bool,
     pub control: bool,
@@ -62,8 +106,21 @@ pub struct KeyboardModifiers {
     pub command: bool,
 }
 
-#[derive(Copy, Clone, Debug)]
+impl KeyboardModifiers {
+    /// Is the platform's primary shortcut modifier held?
+    /// That's command on macOS and control elsewhere.
+    pub fn primary(&self) -> bool {
+        if cfg!(target_os = "macos") {
+            self.command
+        } else {
+            self.control
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Key {
+    /// Letters, digits and punctuation, including the numeric keypad.
     Character(char),
 
     Enter,
@@ -79,6 +136,7 @@ pub enum Key {
     PageUp,
     Backspace,
     Delete,
+    Insert,
     Escape,
     F1,
     F2,
@@ -92,9 +150,42 @@ pub enum Key {
     F10,
     F11,
     F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+
+    /// Modifier keys themselves, so they can be tracked going up and down.
+    Shift,
+    Control,
+    Alt,
+    Meta,
+    CapsLock,
+    NumLock,
+    ScrollLock,
+
+    PrintScreen,
+    Pause,
+    ContextMenu,
+
+    MediaPlayPause,
+    MediaStop,
+    MediaTrackNext,
+    MediaTrackPrevious,
+    AudioVolumeUp,
+    AudioVolumeDown,
+    AudioVolumeMute,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+#[derive(Clone, Debug, Eq, PartialEq, Copy, Hash)]
 pub enum HotKey {
     KeyA,
     KeyB,
@@ -123,3 +214,16 @@ pub enum HotKey {
     KeyY,
     KeyZ,
 }
+
+impl HotKey {
+    /// The letter for the hotkey.
+    pub fn char(&self) -> char {
+        (b'a' + *self as u8) as char
+    }
+
+    /// Does a key press trigger this hotkey? The platform's
+    /// primary modifier must be held.
+    pub fn matches(&self, key: &Key, mods: &KeyboardModifiers) -> bool {
+        KeyChord::from(*self).matches(key, mods)
+    }
+}
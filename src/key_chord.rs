@@ -0,0 +1,257 @@
+use crate::*;
+use std::fmt;
+use std::str::FromStr;
+
+/// A key together with the modifiers which must be held, for
+/// binding commands to keyboard shortcuts.
+///
+/// Parse from strings like `"Cmd+Shift+S"`, `"Ctrl+Alt+Delete"` or
+/// `"F5"`. `Primary` (or `CmdOrCtrl`) means command on macOS and
+/// control elsewhere.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct KeyChord {
+    pub key: Key,
+    pub mods: KeyboardModifiers,
+}
+
+impl KeyChord {
+    pub fn new(key: Key, mods: KeyboardModifiers) -> Self {
+        // Letters are stored lowercase, with shift as a modifier.
+        let key = match key {
+            Key::Character(c) => Key::Character(c.to_ascii_lowercase()),
+            key => key,
+        };
+        Self { key, mods }
+    }
+
+    /// The chord for a key with the platform's primary modifier.
+    pub fn primary(key: Key) -> Self {
+        Self::new(key, primary_mods())
+    }
+
+    /// Does a key press trigger this chord?
+    pub fn matches(&self, key: &Key, mods: &KeyboardModifiers) -> bool {
+        *self == KeyChord::new(*key, *mods)
+    }
+}
+
+impl From<HotKey> for KeyChord {
+    fn from(hotkey: HotKey) -> Self {
+        KeyChord::primary(Key::Character(hotkey.char()))
+    }
+}
+
+fn primary_mods() -> KeyboardModifiers {
+    KeyboardModifiers {
+        command: cfg!(target_os = "macos"),
+        control: !cfg!(target_os = "macos"),
+        ..Default::default()
+    }
+}
+
+/// Error returned when a string isn't a valid `KeyChord`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseKeyChordError {
+    part: String,
+}
+
+impl fmt::Display for ParseKeyChordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid key chord component: {:?}", self.part)
+    }
+}
+
+impl std::error::Error for ParseKeyChordError {}
+
+impl FromStr for KeyChord {
+    type Err = ParseKeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |part: &str| ParseKeyChordError { part: part.into() };
+
+        // "Cmd++" binds the plus key.
+        let (mods_str, key_str) = match s.strip_suffix("++") {
+            Some(rest) => (rest, "+"),
+            None => match s.rfind('+') {
+                Some(i) => (&s[..i], &s[i + 1..]),
+                None => ("", s),
+            },
+        };
+
+        let mut mods = KeyboardModifiers::default();
+        for part in mods_str.split('+').filter(|p| !p.is_empty()) {
+            match part.trim().to_ascii_lowercase().as_str() {
+                "shift" => mods.shift = true,
+                "ctrl" | "control" => mods.control = true,
+                "alt" | "option" | "opt" => mods.alt = true,
+                "cmd" | "command" | "meta" | "super" | "win" => mods.command = true,
+                "primary" | "cmdorctrl" | "mod" => {
+                    let primary = primary_mods();
+                    mods.command |= primary.command;
+                    mods.control |= primary.control;
+                }
+                _ => return Err(err(part)),
+            }
+        }
+
+        let key = parse_key(key_str.trim()).ok_or_else(|| err(key_str))?;
+        Ok(KeyChord::new(key, mods))
+    }
+}
+
+fn parse_key(s: &str) -> Option<Key> {
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Key::Character(c));
+    }
+
+    Some(match s.to_ascii_lowercase().as_str() {
+        "plus" => Key::Character('+'),
+        "enter" | "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "space" => Key::Space,
+        "down" | "arrowdown" => Key::ArrowDown,
+        "left" | "arrowleft" => Key::ArrowLeft,
+        "right" | "arrowright" => Key::ArrowRight,
+        "up" | "arrowup" => Key::ArrowUp,
+        "end" => Key::End,
+        "home" => Key::Home,
+        "pagedown" => Key::PageDown,
+        "pageup" => Key::PageUp,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "insert" | "ins" => Key::Insert,
+        "escape" | "esc" => Key::Escape,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        "f13" => Key::F13,
+        "f14" => Key::F14,
+        "f15" => Key::F15,
+        "f16" => Key::F16,
+        "f17" => Key::F17,
+        "f18" => Key::F18,
+        "f19" => Key::F19,
+        "f20" => Key::F20,
+        "f21" => Key::F21,
+        "f22" => Key::F22,
+        "f23" => Key::F23,
+        "f24" => Key::F24,
+        _ => return None,
+    })
+}
+
+impl fmt::Display for KeyChord {
+    /// Formats the chord the way the platform shows shortcuts in
+    /// menus: `⇧⌘S` on macOS and `Ctrl+Shift+S` elsewhere.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mac = cfg!(target_os = "macos");
+        let mods = &self.mods;
+
+        if mac {
+            for (held, symbol) in [
+                (mods.control, "⌃"),
+                (mods.alt, "⌥"),
+                (mods.shift, "⇧"),
+                (mods.command, "⌘"),
+            ] {
+                if held {
+                    f.write_str(symbol)?;
+                }
+            }
+        } else {
+            for (held, name) in [
+                (mods.control, "Ctrl+"),
+                (mods.alt, "Alt+"),
+                (mods.shift, "Shift+"),
+                (mods.command, "Win+"),
+            ] {
+                if held {
+                    f.write_str(name)?;
+                }
+            }
+        }
+
+        let (mac_name, name) = match self.key {
+            Key::Character(c) => return write!(f, "{}", c.to_ascii_uppercase()),
+            Key::Enter => ("↩", "Enter"),
+            Key::Tab => ("⇥", "Tab"),
+            Key::Space => ("Space", "Space"),
+            Key::ArrowDown => ("↓", "Down"),
+            Key::ArrowLeft => ("←", "Left"),
+            Key::ArrowRight => ("→", "Right"),
+            Key::ArrowUp => ("↑", "Up"),
+            Key::Backspace => ("⌫", "Backspace"),
+            Key::Delete => ("⌦", "Delete"),
+            Key::Escape => ("⎋", "Esc"),
+            key => return write!(f, "{:?}", key),
+        };
+        f.write_str(if mac { mac_name } else { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_key_chord() {
+        let chord: KeyChord = "Cmd+Shift+S".parse().unwrap();
+        assert_eq!(chord.key, Key::Character('s'));
+        assert!(chord.mods.command && chord.mods.shift);
+        assert!(!chord.mods.control && !chord.mods.alt);
+
+        let chord: KeyChord = "ctrl+alt+delete".parse().unwrap();
+        assert_eq!(chord.key, Key::Delete);
+        assert!(chord.mods.control && chord.mods.alt);
+
+        let chord: KeyChord = "F5".parse().unwrap();
+        assert_eq!(chord, KeyChord::new(Key::F5, Default::default()));
+
+        let chord: KeyChord = "Ctrl++".parse().unwrap();
+        assert_eq!(chord.key, Key::Character('+'));
+
+        assert_eq!(
+            "Primary+N".parse::<KeyChord>(),
+            Ok(KeyChord::from(HotKey::KeyN))
+        );
+
+        assert!("Hyper+X".parse::<KeyChord>().is_err());
+        assert!("Ctrl+Nope".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn test_key_chord_matches() {
+        let chord: KeyChord = "Ctrl+Shift+S".parse().unwrap();
+        let mods = KeyboardModifiers {
+            control: true,
+            shift: true,
+            ..Default::default()
+        };
+        // Shift uppercases the key we get from the backend.
+        assert!(chord.matches(&Key::Character('S'), &mods));
+        assert!(!chord.matches(&Key::Character('S'), &Default::default()));
+    }
+
+    #[test]
+    fn test_key_chord_display() {
+        let chord: KeyChord = "Ctrl+Shift+S".parse().unwrap();
+        let f5: KeyChord = "F5".parse().unwrap();
+        if cfg!(target_os = "macos") {
+            assert_eq!(chord.to_string(), "⌃⇧S");
+        } else {
+            assert_eq!(chord.to_string(), "Ctrl+Shift+S");
+        }
+        assert_eq!(f5.to_string(), "F5");
+    }
+}
@@ -1,6 +1,30 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Focuses on part of a value. Lenses which may find nothing, such as
+/// `IndexLens` or `SomeLens`, return `None` from `try_focus` and
+/// `try_focus_mut`, and panic from `focus` and `focus_mut`.
 pub trait Lens<T, U>: Clone + Copy + 'static {
     fn focus<'a>(&self, data: &'a T) -> &'a U;
     fn focus_mut<'a>(&self, data: &'a mut T) -> &'a mut U;
+
+    fn try_focus<'a>(&self, data: &'a T) -> Option<&'a U> {
+        Some(self.focus(data))
+    }
+
+    fn try_focus_mut<'a>(&self, data: &'a mut T) -> Option<&'a mut U> {
+        Some(self.focus_mut(data))
+    }
+
+    /// Focuses further, through another lens.
+    fn then<V, L: Lens<U, V>>(self, next: L) -> Then<Self, L, U> {
+        Then {
+            first: self,
+            next,
+            phantom: PhantomData,
+        }
+    }
 }
 
 #[macro_export]
@@ -18,3 +42,452 @@ macro_rules! make_lens {
         }
     };
 }
+
+/// Lens to a field, from functions which borrow it. These are what
+/// `#[derive(Lens)]` generates.
+pub struct FieldLens<T, U> {
+    get: fn(&T) -> &U,
+    get_mut: fn(&mut T) -> &mut U,
+}
+
+impl<T, U> FieldLens<T, U> {
+    pub fn new(get: fn(&T) -> &U, get_mut: fn(&mut T) -> &mut U) -> Self {
+        Self { get, get_mut }
+    }
+}
+
+impl<T, U> Clone for FieldLens<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, U> Copy for FieldLens<T, U> {}
+
+impl<T: 'static, U: 'static> Lens<T, U> for FieldLens<T, U> {
+    fn focus<'a>(&self, data: &'a T) -> &'a U {
+        (self.get)(data)
+    }
+    fn focus_mut<'a>(&self, data: &'a mut T) -> &'a mut U {
+        (self.get_mut)(data)
+    }
+}
+
+/// Struct for `Lens::then`.
+pub struct Then<A, B, U> {
+    first: A,
+    next: B,
+    phantom: PhantomData<fn() -> U>,
+}
+
+impl<A: Copy, B: Copy, U> Clone for Then<A, B, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Copy, B: Copy, U> Copy for Then<A, B, U> {}
+
+impl<T, U, V, A, B> Lens<T, V> for Then<A, B, U>
+where
+    A: Lens<T, U>,
+    B: Lens<U, V>,
+    U: 'static,
+{
+    fn focus<'a>(&self, data: &'a T) -> &'a V {
+        self.next.focus(self.first.focus(data))
+    }
+    fn focus_mut<'a>(&self, data: &'a mut T) -> &'a mut V {
+        self.next.focus_mut(self.first.focus_mut(data))
+    }
+    fn try_focus<'a>(&self, data: &'a T) -> Option<&'a V> {
+        self.next.try_focus(self.first.try_focus(data)?)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut T) -> Option<&'a mut V> {
+        self.next.try_focus_mut(self.first.try_focus_mut(data)?)
+    }
+}
+
+/// Lens to an element of a `Vec`, boxed slice or array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexLens(pub usize);
+
+impl<T: 'static> Lens<Vec<T>, T> for IndexLens {
+    fn focus<'a>(&self, data: &'a Vec<T>) -> &'a T {
+        &data[self.0]
+    }
+    fn focus_mut<'a>(&self, data: &'a mut Vec<T>) -> &'a mut T {
+        &mut data[self.0]
+    }
+    fn try_focus<'a>(&self, data: &'a Vec<T>) -> Option<&'a T> {
+        data.get(self.0)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut Vec<T>) -> Option<&'a mut T> {
+        data.get_mut(self.0)
+    }
+}
+
+impl<T: 'static> Lens<Box<[T]>, T> for IndexLens {
+    fn focus<'a>(&self, data: &'a Box<[T]>) -> &'a T {
+        &data[self.0]
+    }
+    fn focus_mut<'a>(&self, data: &'a mut Box<[T]>) -> &'a mut T {
+        &mut data[self.0]
+    }
+    fn try_focus<'a>(&self, data: &'a Box<[T]>) -> Option<&'a T> {
+        data.get(self.0)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut Box<[T]>) -> Option<&'a mut T> {
+        data.get_mut(self.0)
+    }
+}
+
+impl<T: 'static, const N: usize> Lens<[T; N], T> for IndexLens {
+    fn focus<'a>(&self, data: &'a [T; N]) -> &'a T {
+        &data[self.0]
+    }
+    fn focus_mut<'a>(&self, data: &'a mut [T; N]) -> &'a mut T {
+        &mut data[self.0]
+    }
+    fn try_focus<'a>(&self, data: &'a [T; N]) -> Option<&'a T> {
+        data.get(self.0)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut [T; N]) -> Option<&'a mut T> {
+        data.get_mut(self.0)
+    }
+}
+
+/// Lens to the value for a key in a `HashMap` or `BTreeMap`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyLens<K>(pub K);
+
+impl<K: Hash + Eq + Copy + 'static, V: 'static> Lens<HashMap<K, V>, V> for KeyLens<K> {
+    fn focus<'a>(&self, data: &'a HashMap<K, V>) -> &'a V {
+        self.try_focus(data).expect("key not in map")
+    }
+    fn focus_mut<'a>(&self, data: &'a mut HashMap<K, V>) -> &'a mut V {
+        self.try_focus_mut(data).expect("key not in map")
+    }
+    fn try_focus<'a>(&self, data: &'a HashMap<K, V>) -> Option<&'a V> {
+        data.get(&self.0)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut HashMap<K, V>) -> Option<&'a mut V> {
+        data.get_mut(&self.0)
+    }
+}
+
+impl<K: Ord + Copy + 'static, V: 'static> Lens<BTreeMap<K, V>, V> for KeyLens<K> {
+    fn focus<'a>(&self, data: &'a BTreeMap<K, V>) -> &'a V {
+        self.try_focus(data).expect("key not in map")
+    }
+    fn focus_mut<'a>(&self, data: &'a mut BTreeMap<K, V>) -> &'a mut V {
+        self.try_focus_mut(data).expect("key not in map")
+    }
+    fn try_focus<'a>(&self, data: &'a BTreeMap<K, V>) -> Option<&'a V> {
+        data.get(&self.0)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut BTreeMap<K, V>) -> Option<&'a mut V> {
+        data.get_mut(&self.0)
+    }
+}
+
+/// Lens to field `N` of a tuple, for tuples of up to four elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TupleLens<const N: usize>;
+
+macro_rules! impl_tuple_field {
+    ($n: literal, $field: tt, $to: ident, ($($t: ident),*)) => {
+        impl<$($t: 'static),*> Lens<($($t,)*), $to> for TupleLens<$n> {
+            fn focus<'a>(&self, data: &'a ($($t,)*)) -> &'a $to {
+                &data.$field
+            }
+            fn focus_mut<'a>(&self, data: &'a mut ($($t,)*)) -> &'a mut $to {
+                &mut data.$field
+            }
+        }
+    };
+}
+
+impl_tuple_field!(0, 0, A, (A, B));
+impl_tuple_field!(1, 1, B, (A, B));
+impl_tuple_field!(0, 0, A, (A, B, C));
+impl_tuple_field!(1, 1, B, (A, B, C));
+impl_tuple_field!(2, 2, C, (A, B, C));
+impl_tuple_field!(0, 0, A, (A, B, C, D));
+impl_tuple_field!(1, 1, B, (A, B, C, D));
+impl_tuple_field!(2, 2, C, (A, B, C, D));
+impl_tuple_field!(3, 3, D, (A, B, C, D));
+
+/// Lens to the value inside an `Option`, if there is one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SomeLens;
+
+impl<T: 'static> Lens<Option<T>, T> for SomeLens {
+    fn focus<'a>(&self, data: &'a Option<T>) -> &'a T {
+        data.as_ref().expect("option is None")
+    }
+    fn focus_mut<'a>(&self, data: &'a mut Option<T>) -> &'a mut T {
+        data.as_mut().expect("option is None")
+    }
+    fn try_focus<'a>(&self, data: &'a Option<T>) -> Option<&'a T> {
+        data.as_ref()
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut Option<T>) -> Option<&'a mut T> {
+        data.as_mut()
+    }
+}
+
+/// Lens to the data of an enum variant, from functions which match it.
+///
+/// ```ignore
+/// let radius = VariantLens::new(
+///     |s: &Shape| match s {
+///         Shape::Circle(r) => Some(r),
+///         _ => None,
+///     },
+///     |s: &mut Shape| match s {
+///         Shape::Circle(r) => Some(r),
+///         _ => None,
+///     },
+/// );
+/// ```
+pub struct VariantLens<T, U> {
+    get: fn(&T) -> Option<&U>,
+    get_mut: fn(&mut T) -> Option<&mut U>,
+}
+
+impl<T, U> VariantLens<T, U> {
+    pub fn new(get: fn(&T) -> Option<&U>, get_mut: fn(&mut T) -> Option<&mut U>) -> Self {
+        Self { get, get_mut }
+    }
+}
+
+impl<T, U> Clone for VariantLens<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, U> Copy for VariantLens<T, U> {}
+
+impl<T: 'static, U: 'static> Lens<T, U> for VariantLens<T, U> {
+    fn focus<'a>(&self, data: &'a T) -> &'a U {
+        (self.get)(data).expect("enum is a different variant")
+    }
+    fn focus_mut<'a>(&self, data: &'a mut T) -> &'a mut U {
+        (self.get_mut)(data).expect("enum is a different variant")
+    }
+    fn try_focus<'a>(&self, data: &'a T) -> Option<&'a U> {
+        (self.get)(data)
+    }
+    fn try_focus_mut<'a>(&self, data: &'a mut T) -> Option<&'a mut U> {
+        (self.get_mut)(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Lens, Default)]
+    struct Mixer {
+        gain: f32,
+        muted: bool,
+    }
+
+    #[derive(Lens, Default)]
+    struct App {
+        mixer: Mixer,
+        title: String,
+    }
+
+    #[derive(Lens, Default)]
+    struct Track {
+        volume: f32,
+        name: String,
+    }
+
+    #[derive(Lens, Default)]
+    struct Session {
+        tracks: Vec<Track>,
+    }
+
+    enum Shape {
+        Circle(f32),
+        Square,
+    }
+
+    #[derive(Lens)]
+    struct Labeled<T: Clone> {
+        label: String,
+        value: T,
+    }
+
+    #[test]
+    fn test_derive_lens() {
+        let mut app = App::default();
+        *App::title().focus_mut(&mut app) = "mix".into();
+        *App::mixer().then(Mixer::gain()).focus_mut(&mut app) = 0.5;
+        assert_eq!(app.title, "mix");
+        assert_eq!(app.mixer.gain, 0.5);
+        assert!(!*App::mixer().then(Mixer::muted()).focus(&app));
+
+        let labeled = Labeled {
+            label: "count".into(),
+            value: 3,
+        };
+        assert_eq!(Labeled::<i32>::label().focus(&labeled), "count");
+        assert_eq!(*Labeled::value().focus(&labeled), 3);
+    }
+
+    #[test]
+    fn test_derive_lens_binding() {
+        let mut cx = Context::new();
+        let ui = state(App::default, |s, _| {
+            hslider(bind(s, App::mixer().then(Mixer::gain())))
+        });
+        let s = StateHandle::<App>::new(cx.root_id);
+
+        let root = cx.root_id;
+        cx.init_state(root, &App::default);
+        let gain = bind(s, App::mixer().then(Mixer::gain()));
+        *gain.get_mut(&mut cx) = 0.25;
+        assert_eq!(cx[s].mixer.gain, 0.25);
+
+        let mut nodes = vec![];
+        ui.access(cx.root_id, &mut cx, &mut nodes);
+        let (_, slider) = nodes
+            .iter()
+            .find(|(_, node)| node.role() == accesskit::Role::Slider)
+            .unwrap();
+        assert_eq!(slider.numeric_value(), Some(0.25));
+    }
+
+    #[test]
+    fn test_collection_lenses() {
+        let mut session = Session {
+            tracks: vec![Track {
+                volume: 0.5,
+                ..Default::default()
+            }],
+        };
+        let volume = |i| Session::tracks().then(IndexLens(i)).then(Track::volume());
+        *volume(0).focus_mut(&mut session) = 0.75;
+        assert_eq!(session.tracks[0].volume, 0.75);
+        assert!(volume(1).try_focus(&session).is_none());
+        assert!(volume(1).try_focus_mut(&mut session).is_none());
+
+        let mut array = [1, 2, 3];
+        *IndexLens(2).focus_mut(&mut array) = 4;
+        assert_eq!(array, [1, 2, 4]);
+        let boxed: Box<[i32]> = vec![5].into();
+        assert_eq!(IndexLens(0).try_focus(&boxed), Some(&5));
+        assert_eq!(IndexLens(1).try_focus(&boxed), None);
+
+        let mut hash_map = HashMap::new();
+        hash_map.insert(1, "one");
+        assert_eq!(KeyLens(1).try_focus(&hash_map), Some(&"one"));
+        assert_eq!(KeyLens(2).try_focus(&hash_map), None);
+        let mut btree_map = BTreeMap::new();
+        btree_map.insert("a", 1);
+        *KeyLens("a").focus_mut(&mut btree_map) += 1;
+        assert_eq!(btree_map["a"], 2);
+        assert_eq!(KeyLens("b").try_focus_mut(&mut btree_map), None);
+
+        let mut pair = (1, "x");
+        *TupleLens::<0>.focus_mut(&mut pair) = 2;
+        assert_eq!(*TupleLens::<1>.focus(&pair), "x");
+        assert_eq!(pair.0, 2);
+        assert_eq!(*TupleLens::<2>.focus(&(1, 2, 3)), 3);
+
+        let mut maybe = Some(1);
+        *SomeLens.focus_mut(&mut maybe) = 2;
+        assert_eq!(maybe, Some(2));
+        assert_eq!(SomeLens.try_focus(&None::<i32>), None);
+
+        let radius = VariantLens::new(
+            |s: &Shape| match s {
+                Shape::Circle(r) => Some(r),
+                _ => None,
+            },
+            |s: &mut Shape| match s {
+                Shape::Circle(r) => Some(r),
+                _ => None,
+            },
+        );
+        let mut shape = Shape::Circle(1.0);
+        *radius.focus_mut(&mut shape) = 2.0;
+        assert_eq!(radius.try_focus(&shape), Some(&2.0));
+        assert_eq!(radius.try_focus(&Shape::Square), None);
+    }
+
+    #[test]
+    fn test_list_row_binding() {
+        let mut cx = Context::new();
+        let init = || Session {
+            tracks: vec![
+                Track {
+                    volume: 0.5,
+                    name: "Bass".into(),
+                },
+                Track {
+                    volume: 0.25,
+                    name: "Drums".into(),
+                },
+            ],
+        };
+        let volume = |s, i| {
+            bind(
+                s,
+                Session::tracks().then(IndexLens(i)).then(Track::volume()),
+            )
+        };
+        let name = |s, i| bind(s, Session::tracks().then(IndexLens(i)).then(Track::name()));
+        let ui = state(init, move |s, _| {
+            list(vec![0, 1, 2], move |i| {
+                hstack((hslider(volume(s, *i)), text_field(name(s, *i))))
+            })
+        });
+        let s = StateHandle::<Session>::new(cx.root_id);
+
+        let root = cx.root_id;
+        cx.init_state(root, &init);
+        assert_eq!(volume(s, 1).try_get(&cx), Some(&0.25));
+        assert_eq!(volume(s, 2).try_get(&cx), None);
+        assert_eq!(volume(s, 2).try_with_mut(&mut cx, |v| *v = 1.0), None);
+        setter(volume(s, 2))(1.0, &mut cx);
+        setter(volume(s, 0))(0.75, &mut cx);
+        assert_eq!(cx[s].tracks[0].volume, 0.75);
+
+        // The row for the missing track still builds its nodes.
+        let mut nodes = vec![];
+        ui.access(cx.root_id, &mut cx, &mut nodes);
+        let values: Vec<_> = nodes
+            .iter()
+            .filter(|(_, node)| node.role() == accesskit::Role::Slider)
+            .map(|(_, node)| node.numeric_value())
+            .collect();
+        assert_eq!(values, vec![Some(0.75), Some(0.25), Some(0.0)]);
+
+        let fields: Vec<_> = nodes
+            .iter()
+            .filter(|(_, node)| node.role() == accesskit::Role::TextField)
+            .collect();
+        let values: Vec<_> = fields.iter().map(|(_, node)| node.value()).collect();
+        assert_eq!(values, vec![Some("Bass"), Some("Drums"), Some("")]);
+
+        // Editing the missing track's name does nothing.
+        let target = fields[2].0;
+        cx.process(
+            &ui,
+            &Event::Access(accesskit::ActionRequest {
+                action: accesskit::Action::SetValue,
+                target,
+                data: Some(accesskit::ActionData::Value("Keys".into())),
+            }),
+        );
+        assert_eq!(cx[s].tracks.len(), 2);
+    }
+}
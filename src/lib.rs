@@ -3,6 +3,14 @@
 use vger::color::*;
 use vger::{LineMetrics, PaintIndex, Vger};
 
+// So views outside rui can build accessibility nodes.
+pub use accesskit;
+
+pub use rui_derive::Lens;
+
+// So `#[derive(Lens)]`, which refers to `::rui`, works within rui.
+extern crate self as rui;
+
 #[cfg(feature = "winit")]
 #[macro_use]
 extern crate lazy_static;
@@ -19,6 +27,9 @@ pub use viewtuple::*;
 mod event;
 pub use event::*;
 
+mod key_chord;
+pub use key_chord::*;
+
 mod lens;
 pub use lens::*;
 
@@ -40,6 +51,12 @@ pub use modifiers::*;
 mod colors;
 pub use colors::*;
 
+mod theme;
+pub use theme::*;
+
+mod audit;
+pub use audit::*;
+
 mod align;
 pub use align::*;
 
@@ -69,3 +86,16 @@ mod tests {
         });
     }
 }
+
+// LOCAL BUILD SHIM (never committed): vger 0.2.5 isn't available offline.
+#[doc(hidden)]
+pub trait VgerShim {
+    fn shim() -> Self;
+    fn shim_encode(&mut self, d: &wgpu::Device, r: &wgpu::RenderPassDescriptor, q: &wgpu::Queue);
+}
+impl VgerShim for vger::Vger {
+    fn shim() -> Self {
+        unimplemented!()
+    }
+    fn shim_encode(&mut self, _: &wgpu::Device, _: &wgpu::RenderPassDescriptor, _: &wgpu::Queue) {}
+}
@@ -1,8 +1,25 @@
 use crate::*;
-use accesskit::Role;
+use accesskit::{Action, ActionRequest, Role};
 
 /// Modifiers common to all views.
 pub trait Modifiers: View + Sized {
+    /// Sets the name assistive technologies read for the view's
+    /// accessibility node, such as the one added by `role`.
+    fn accessibility_label(self, label: &str) -> AccessibilityView<Self> {
+        AccessibilityView::new(self, AccessProperty::Label(label.into()))
+    }
+
+    /// Describes what happens when the view is used.
+    fn accessibility_hint(self, hint: &str) -> AccessibilityView<Self> {
+        AccessibilityView::new(self, AccessProperty::Hint(hint.into()))
+    }
+
+    /// Sets the value assistive technologies read for the view, for
+    /// views whose appearance shows it.
+    fn accessibility_value(self, value: &str) -> AccessibilityView<Self> {
+        AccessibilityView::new(self, AccessProperty::Value(value.into()))
+    }
+
     /// Calls a closure after rendering with context and delta time.
     fn anim<F: Fn(&mut Context, f32) + 'static + Clone>(self, func: F) -> AnimView<Self, F> {
         AnimView::new(self, func)
@@ -13,11 +30,25 @@ pub trait Modifiers: View + Sized {
         Background::new(self, background)
     }
 
-    /// Adds a menu command.
+    /// Presents a modal alert over the view while `is_presented` is true.
+    /// Escape triggers the cancel button and Enter the default button.
+    /// Only the view's own rect is covered, so attach it to the root view.
+    fn alert<B: Binding<bool>>(
+        self,
+        is_presented: B,
+        title: &str,
+        message: &str,
+        buttons: Vec<AlertButton>,
+    ) -> Sheet<Self, B, AnyView> {
+        Sheet::alert(self, is_presented, title, message, buttons)
+    }
+
+    /// Adds a menu command, optionally with a key chord which triggers it.
+    /// A plain `HotKey` converts with `.into()`.
     fn command<F: Fn(&mut Context) + 'static>(
         self,
         name: &str,
-        key: Option<HotKey>,
+        key: Option<KeyChord>,
         f: F,
     ) -> Command<Self, F> {
         Command::new(self, name.into(), key, f)
@@ -28,6 +59,18 @@ pub trait Modifiers: View + Sized {
         CommandGroup::new(self, cmds)
     }
 
+    /// Opens a searchable list of the app's commands with
+    /// Cmd+Shift+P (Ctrl+Shift+P on other platforms).
+    fn command_palette(self) -> CommandPalette<Self> {
+        CommandPalette::new(self)
+    }
+
+    /// Shows a popup menu of commands on right-click, or a long press
+    /// on touch screens.
+    fn context_menu<C: CommandTuple + 'static>(self, cmds: C) -> ContextMenu<Self, C> {
+        ContextMenu::new(self, cmds)
+    }
+
     /// Calls a function in response to a drag.
     fn drag<F: Fn(&mut Context, LocalOffset, GestureState, Option<MouseButton>) + 'static>(
         self,
@@ -69,15 +112,41 @@ pub trait Modifiers: View + Sized {
         FullscreenView::new(self)
     }
 
+    /// Makes the view reachable with Tab and draws a focus ring when focused.
+    fn focusable(self) -> Focusable<Self> {
+        Focusable::new(self)
+    }
+
+    /// Registers a handle which `Context::focus` can use to
+    /// move focus to the first focusable view in this subtree.
+    fn focus_handle(self, handle: FocusHandle) -> FocusHandleView<Self> {
+        FocusHandleView::new(self, handle)
+    }
+
     /// Calls a function with the view's geometry after layout runs.
     /// Currently only the view's size is returned.
     fn geom<F: Fn(&mut Context, LocalSize, LocalToWorld) + 'static>(self, f: F) -> Geom<Self, F> {
         Geom::new(self, f)
     }
 
-    /// Responds to keyboard events
-    fn key<F: Fn(&mut Context, Key) + 'static>(self, f: F) -> KeyView<Self, F> {
-        KeyView::new(self, f)
+    /// Calls a function when a key is pressed, with the modifiers held
+    /// and whether the press is an auto-repeat.
+    ///
+    /// Keys go to the focused view first, then bubble up through its
+    /// ancestors until a handler calls `Context::set_handled`.
+    fn key_down<F: Fn(&mut Context, Key, KeyboardModifiers, bool) + 'static>(
+        self,
+        f: F,
+    ) -> KeyDownView<Self, F> {
+        KeyDownView::new(self, f)
+    }
+
+    /// Calls a function when a key is released, with the modifiers held.
+    fn key_up<F: Fn(&mut Context, Key, KeyboardModifiers) + 'static>(
+        self,
+        f: F,
+    ) -> KeyUpView<Self, F> {
+        KeyUpView::new(self, f)
     }
 
     /// Applies an offset to the view in local space.
@@ -85,6 +154,39 @@ pub trait Modifiers: View + Sized {
         Offset::new(self, offset.into())
     }
 
+    /// Responds to an action requested by an assistive technology, such
+    /// as a screen reader. Use inside `role`, so the action is for the
+    /// view's node: `.on_access_action(..).role(..)`.
+    fn on_access_action<A: 'static, F: Fn(&mut Context, &ActionRequest) -> A + 'static>(
+        self,
+        action: Action,
+        f: F,
+    ) -> AccessActionView<Self, F> {
+        AccessActionView::new(self, action, f)
+    }
+
+    /// Calls a closure when the view joins the tree, such as to start a
+    /// meter for a panel which is only sometimes shown.
+    fn on_appear<F: Fn(&mut Context) + 'static>(self, f: F) -> LifecycleView<Self> {
+        LifecycleView::appear(self, f)
+    }
+
+    /// Calls a closure with the old and new values when a binding's
+    /// value changes, checked after each event is processed.
+    fn on_change<S, B, F>(self, binding: B, f: F) -> ChangeView<Self, B, F, S>
+    where
+        S: PartialEq + Clone + 'static,
+        B: Binding<S>,
+        F: Fn(&mut Context, &S, &S) + 'static,
+    {
+        ChangeView::new(self, binding, f)
+    }
+
+    /// Calls a closure when the view leaves the tree.
+    fn on_disappear<F: Fn(&mut Context) + 'static>(self, f: F) -> LifecycleView<Self> {
+        LifecycleView::disappear(self, f)
+    }
+
     /// Adds space around a view. Can be either `Auto` or `Px(number_of_pixels)`
     fn padding(self, param: impl Into<PaddingParam>) -> Padding<Self> {
         Padding::new(self, param.into())
@@ -95,6 +197,13 @@ pub trait Modifiers: View + Sized {
         RoleView::new(self, role)
     }
 
+    /// Presents a modal view over the view while `is_presented` is true.
+    /// Escape dismisses it. Only the view's own rect is covered, so attach
+    /// it to the root view.
+    fn sheet<B: Binding<bool>, S: View>(self, is_presented: B, sheet: S) -> Sheet<Self, B, S> {
+        Sheet::new(self, is_presented, sheet)
+    }
+
     /// Constrains the size of a view.
     fn size<Sz: Into<LocalSize>>(self, size: Sz) -> Size<Self> {
         Size::new(self, size.into())
@@ -111,6 +220,19 @@ pub trait Modifiers: View + Sized {
         TapA::new(self, action)
     }
 
+    /// Responds to text input: `Event::TextInput`, `Event::ImePreedit`
+    /// and `Event::ImeCommit`. Routed like `key_down`.
+    fn text_input<F: Fn(&mut Context, &Event) + 'static>(self, f: F) -> TextInputView<Self, F> {
+        TextInputView::new(self, f)
+    }
+
+    /// Shows a floating label after the pointer rests over the view.
+    /// Pass a string or any view. The text also describes the view
+    /// for accessibility.
+    fn tooltip<T: View + Clone>(self, tip: T) -> Tooltip<Self, T> {
+        Tooltip::new(self, tip)
+    }
+
     /// Specify the title of the window.
     fn window_title(self, title: &str) -> TitleView<Self> {
         TitleView::new(self, title)
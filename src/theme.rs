@@ -0,0 +1,99 @@
+use crate::*;
+
+/// Colors, sizes and spacing for the built-in controls.
+///
+/// The theme lives in the environment, so set it for a subtree with
+/// `.env(Theme::light())`. Without one, controls use `Theme::dark()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    /// Text and labels.
+    pub text_color: Color,
+    /// Text which can't be interacted with.
+    pub disabled_text_color: Color,
+    /// Button faces and slider tracks.
+    pub button_color: Color,
+    /// Button faces under the pointer.
+    pub button_hover_color: Color,
+    /// Backgrounds of toggles, knobs and popups.
+    pub control_background: Color,
+    /// Slider thumbs, knob arcs and the "on" state of toggles.
+    pub accent_color: Color,
+    /// Filled part of sliders and the background of "on" toggles.
+    pub accent_background: Color,
+    pub corner_radius: f32,
+    pub font_size: u32,
+    /// Padding inside controls like buttons.
+    pub spacing: f32,
+}
+
+impl Theme {
+    /// Light text on dark controls.
+    pub fn dark() -> Self {
+        Self {
+            text_color: TEXT_COLOR,
+            disabled_text_color: MEDIUM_GRAY,
+            button_color: BUTTON_BACKGROUND_COLOR,
+            button_hover_color: BUTTON_HOVER_COLOR,
+            control_background: CONTROL_BACKGROUND,
+            accent_color: AZURE_HIGHLIGHT,
+            accent_background: AZURE_HIGHLIGHT_BACKGROUND,
+            corner_radius: BUTTON_CORNER_RADIUS,
+            font_size: Text::DEFAULT_SIZE,
+            spacing: 5.0,
+        }
+    }
+
+    /// Dark text on light controls.
+    pub fn light() -> Self {
+        Self {
+            text_color: Color::hex_const("#1F1F1F"),
+            disabled_text_color: Color::hex_const("#9E9E9E"),
+            button_color: Color::hex_const("#E3E3E6"),
+            button_hover_color: Color::hex_const("#D0D0D4"),
+            control_background: Color::hex_const("#F2F2F5"),
+            accent_color: AZURE_HIGHLIGHT_DARK,
+            accent_background: Color::hex_const("#B8EEFA"),
+            ..Self::dark()
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_theme_env() {
+        let mut cx = Context::new();
+
+        let big = Theme {
+            font_size: 30,
+            ..Theme::light()
+        };
+        let ui = hstack((text("a"), text("b").env(big), text("c")));
+
+        let mut sizes = vec![];
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: [100.0, 100.0].into(),
+                cx: &mut cx,
+                text_bounds: &mut |_, size, _| {
+                    sizes.push(size);
+                    LocalRect::zero()
+                },
+            },
+        );
+
+        // The theme only applies within the subtree it's set on.
+        assert_eq!(sizes, vec![18, 30, 18]);
+        assert_eq!(cx.theme(), Theme::dark());
+    }
+}
@@ -23,13 +23,16 @@ impl<'a> LayoutArgs<'a> {
 }
 
 /// Trait for the unit of UI composition.
+///
+/// Only rui's own views implement `View`. To define one elsewhere,
+/// implement `CustomView` and wrap it with `custom`.
 pub trait View: private::Sealed + 'static {
     /// Builds an AccessKit tree. The node ID for the subtree is returned. All generated nodes are accumulated.
     fn access(
         &self,
         _id: ViewId,
         _cx: &mut Context,
-        _nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        _nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         None
     }
@@ -40,6 +43,9 @@ pub trait View: private::Sealed + 'static {
     /// Determines dirty regions which need repainting.
     fn dirty(&self, _id: ViewId, _xform: LocalToWorld, _cx: &mut Context) {}
 
+    /// Accumulates the IDs of views which can take keyboard focus, in traversal order.
+    fn focus_chain(&self, _id: ViewId, _cx: &mut Context, _chain: &mut Vec<ViewId>) {}
+
     /// Draws the view using vger.
     fn draw(&self, id: ViewId, args: &mut DrawArgs);
 
@@ -0,0 +1,162 @@
+use crate::*;
+use accesskit::{Action, ActionRequest};
+use std::any::Any;
+
+/// Struct for the `on_access_action` modifier.
+pub struct AccessActionView<V, F> {
+    child: V,
+    action: Action,
+    func: F,
+}
+
+impl<V, F, A> AccessActionView<V, F>
+where
+    V: View,
+    F: Fn(&mut Context, &ActionRequest) -> A + 'static,
+{
+    pub fn new(child: V, action: Action, func: F) -> Self {
+        Self {
+            child,
+            action,
+            func,
+        }
+    }
+}
+
+impl<V, F, A> View for AccessActionView<V, F>
+where
+    V: View,
+    F: Fn(&mut Context, &ActionRequest) -> A + 'static,
+    A: 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        if let Some(request) = cx.access_request(event) {
+            if request.action == self.action {
+                actions.push(Box::new((self.func)(cx, request)));
+            }
+        }
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        // Advertised on the node we're under.
+        cx.access_actions.push(self.action);
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V, F> private::Sealed for AccessActionView<V, F> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use accesskit::{ActionData, Role};
+
+    #[derive(Default)]
+    struct Controls {
+        presses: i32,
+        value: f32,
+    }
+
+    make_lens!(ControlsValue, Controls, f32, value);
+
+    fn request(action: Action, target: accesskit::NodeId, data: Option<ActionData>) -> Event {
+        Event::Access(ActionRequest {
+            action,
+            target,
+            data,
+        })
+    }
+
+    #[test]
+    fn test_access_actions() {
+        let mut cx = Context::new();
+
+        let ui = state(Controls::default, |s, _| {
+            vstack((
+                button("press", move |cx| cx[s].presses += 1),
+                hslider(bind(s, ControlsValue {})),
+                rectangle()
+                    .on_access_action(Action::Increment, move |cx, _| cx[s].presses += 10)
+                    .role(Role::SpinButton),
+            ))
+        });
+        let s = StateHandle::<Controls>::new(cx.root_id);
+
+        let update = cx.access_update(&ui).unwrap();
+        let find = |role| {
+            update
+                .nodes
+                .iter()
+                .find(|(_, node)| node.role() == role)
+                .unwrap()
+                .clone()
+        };
+        let (button, button_node) = find(Role::Button);
+        let (slider, slider_node) = find(Role::Slider);
+        let (spin, spin_node) = find(Role::SpinButton);
+        assert!(button_node.supports_action(Action::Default));
+        assert!(button_node.supports_action(Action::Focus));
+        assert!(slider_node.supports_action(Action::SetValue));
+        assert!(spin_node.supports_action(Action::Increment));
+        assert!(!spin_node.supports_action(Action::Default));
+
+        cx.process(&ui, &request(Action::Default, button, None));
+        assert_eq!(cx[s].presses, 1);
+
+        cx.process(&ui, &request(Action::Focus, button, None));
+        assert!(cx.focused_id.is_some());
+
+        cx.process(&ui, &request(Action::Increment, slider, None));
+        assert_eq!(cx[s].value, 0.1);
+        cx.process(
+            &ui,
+            &request(Action::SetValue, slider, Some(ActionData::NumericValue(0.25))),
+        );
+        assert_eq!(cx[s].value, 0.25);
+
+        // Only the targeted node's views respond.
+        cx.process(&ui, &request(Action::Increment, spin, None));
+        assert_eq!((cx[s].presses, cx[s].value), (11, 0.25));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::*;
+use accesskit::NodeBuilder;
+use std::any::Any;
+
+type NodeSetter = Box<dyn Fn(&Context, &mut NodeBuilder)>;
+
+pub(crate) enum AccessProperty {
+    Label(String),
+    Hint(String),
+    Value(String),
+    /// Set by controls from their current state.
+    With(NodeSetter),
+}
+
+/// Struct for the `accessibility_label`, `accessibility_hint` and
+/// `accessibility_value` modifiers.
+pub struct AccessibilityView<V> {
+    child: V,
+    property: AccessProperty,
+}
+
+impl<V> AccessibilityView<V>
+where
+    V: View,
+{
+    pub(crate) fn new(child: V, property: AccessProperty) -> Self {
+        Self { child, property }
+    }
+
+    /// Sets properties of the child's node from the context.
+    pub(crate) fn with(child: V, f: impl Fn(&Context, &mut NodeBuilder) + 'static) -> Self {
+        Self::new(child, AccessProperty::With(Box::new(f)))
+    }
+}
+
+impl<V> View for AccessibilityView<V>
+where
+    V: View,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        let aid = self.child.access(id.child(&0), cx, nodes)?;
+        if let Some((_, builder)) = nodes.iter_mut().find(|(node_id, _)| *node_id == aid) {
+            match &self.property {
+                AccessProperty::Label(label) => builder.set_name(label.as_str()),
+                AccessProperty::Hint(hint) => builder.set_description(hint.as_str()),
+                AccessProperty::Value(value) => builder.set_value(value.as_str()),
+                AccessProperty::With(f) => f(cx, builder),
+            }
+        }
+        Some(aid)
+    }
+}
+
+impl<V> private::Sealed for AccessibilityView<V> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use accesskit::{CheckedState, Role};
+
+    fn find_node(cx: &mut Context, ui: &impl View, role: Role) -> accesskit::Node {
+        let update = cx.access_update(ui).unwrap();
+        update
+            .nodes
+            .into_iter()
+            .find(|(_, node)| node.role() == role)
+            .unwrap()
+            .1
+    }
+
+    #[test]
+    fn test_accessibility_modifiers() {
+        let mut cx = Context::new();
+        let ui = rectangle()
+            .role(Role::Image)
+            .accessibility_label("Logo")
+            .accessibility_hint("Goes home")
+            .accessibility_value("Blue");
+
+        let node = find_node(&mut cx, &ui, Role::Image);
+        assert_eq!(node.name(), Some("Logo"));
+        assert_eq!(node.description(), Some("Goes home"));
+        assert_eq!(node.value(), Some("Blue"));
+    }
+
+    #[test]
+    fn test_control_values() {
+        let mut cx = Context::new();
+        let slider = vslider(0.25, |_, _| ());
+        let node = find_node(&mut cx, &slider, Role::Slider);
+        assert_eq!(node.numeric_value(), Some(0.25));
+        assert_eq!(node.min_numeric_value(), Some(0.0));
+        assert_eq!(node.max_numeric_value(), Some(1.0));
+        assert_eq!(node.numeric_value_step(), Some(ACCESS_STEP as f64));
+
+        let mut cx = Context::new();
+        let switch = state(|| true, |on, _| toggle(on));
+        let node = find_node(&mut cx, &switch, Role::Switch);
+        assert_eq!(node.checked_state(), Some(CheckedState::True));
+    }
+
+    #[test]
+    fn test_access_bounds() {
+        let mut cx = Context::new();
+        let ui = vstack((
+            rectangle().size([100.0, 20.0]).role(Role::Button),
+            rectangle().size([100.0, 30.0]).role(Role::Image),
+        ));
+
+        let sz = [100.0, 50.0].into();
+        cx.window_size = sz;
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: sz.cast_unit(),
+                cx: &mut cx,
+                text_bounds: &mut |_, _, _| LocalRect::zero(),
+            },
+        );
+        cx.update_access_bounds(&ui);
+
+        // The first view is at the top, in AccessKit's y-down space.
+        let update = cx.access_update(&ui).unwrap();
+        let bounds = |role| {
+            let (_, node) = update
+                .nodes
+                .iter()
+                .find(|(_, node)| node.role() == role)
+                .unwrap();
+            let rect = node.bounds().unwrap();
+            (rect.y0, rect.y1)
+        };
+        assert_eq!(bounds(Role::Button), (0.0, 20.0));
+        assert_eq!(bounds(Role::Image), (20.0, 50.0));
+    }
+
+    #[test]
+    fn test_access_bounds_dirty_state() {
+        let mut cx = Context::new();
+        let ui = state(|| 0, |_, _| rectangle().role(Role::Button));
+
+        let sz = [100.0, 50.0].into();
+        cx.window_size = sz;
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: sz.cast_unit(),
+                cx: &mut cx,
+                text_bounds: &mut |_, _, _| LocalRect::zero(),
+            },
+        );
+        let s = StateHandle::<i32>::new(cx.root_id);
+        cx[s] = 1;
+        let button_bounds = |cx: &mut Context| {
+            let update = cx.access_update(&ui).unwrap();
+            let (_, node) = update
+                .nodes
+                .iter()
+                .find(|(_, node)| node.role() == Role::Button)
+                .unwrap();
+            node.bounds()
+        };
+
+        // Finding dirty regions stops at the dirty state...
+        ui.dirty(cx.root_id, LocalToWorld::identity(), &mut cx);
+        assert!(button_bounds(&mut cx).is_none());
+
+        // ...but the button is still placed.
+        cx.update_access_bounds(&ui);
+        assert!(button_bounds(&mut cx).is_some());
+    }
+}
@@ -54,6 +54,10 @@ impl View for AnyView {
         self.child.commands(id.child(&self.id()), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&self.id()), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         self.child.gc(id.child(&self.id()), cx, map)
     }
@@ -62,7 +66,7 @@ impl View for AnyView {
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         self.child.access(id.child(&self.id()), cx, nodes)
     }
@@ -49,6 +49,11 @@ where
         self.background.commands(id.child(&1), cx, cmds);
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain);
+        self.background.focus_chain(id.child(&1), cx, chain);
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         self.child.gc(id.child(&0), cx, map);
         self.background.gc(id.child(&1), cx, map);
@@ -58,11 +63,13 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         // XXX: if we were to create a node here, what role would it be?
         //      could print a warning if there is an node produced by background.
-        self.child.access(id.child(&0), cx, nodes)
+        cx.access_over(id.child(&1), &self.background, |cx| {
+            self.child.access(id.child(&0), cx, nodes)
+        })
     }
 }
 
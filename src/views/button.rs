@@ -12,20 +12,24 @@ pub fn button<A: 'static, F: Fn(&mut Context) -> A + 'static + Clone>(
         || false,
         move |hovering, cx| {
             let f = f.clone();
+            let theme = cx.theme();
             view.clone()
-                .padding(Auto)
-                .background(rectangle().corner_radius(BUTTON_CORNER_RADIUS).color(
-                    if cx[hovering] {
-                        BUTTON_HOVER_COLOR
-                    } else {
-                        BUTTON_BACKGROUND_COLOR
-                    },
-                ))
+                .padding(theme.spacing)
+                .background(
+                    rectangle()
+                        .corner_radius(theme.corner_radius)
+                        .color(if cx[hovering] {
+                            theme.button_hover_color
+                        } else {
+                            theme.button_color
+                        }),
+                )
                 .tap(move |cx| f(cx))
                 .hover(move |cx, inside| {
                     cx[hovering] = inside;
                 })
                 .role(Role::Button)
+                .focusable()
         },
     )
 }
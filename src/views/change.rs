@@ -0,0 +1,192 @@
+use crate::*;
+use std::any::Any;
+use std::rc::Rc;
+
+/// Struct for the `on_change` modifier.
+pub struct ChangeView<V, B, F, S> {
+    child: V,
+    binding: B,
+    func: Rc<F>,
+    phantom: std::marker::PhantomData<fn() -> S>,
+}
+
+impl<V, B, F, S> ChangeView<V, B, F, S>
+where
+    V: View,
+    B: Binding<S>,
+    F: Fn(&mut Context, &S, &S) + 'static,
+    S: PartialEq + Clone + 'static,
+{
+    pub fn new(child: V, binding: B, func: F) -> Self {
+        Self {
+            child,
+            binding,
+            func: Rc::new(func),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<V, B, F, S> View for ChangeView<V, B, F, S>
+where
+    V: View,
+    B: Binding<S>,
+    F: Fn(&mut Context, &S, &S) + 'static,
+    S: PartialEq + Clone + 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx)
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+
+        // Changes are counted from when the view joins the tree.
+        if !cx.change_snapshots.contains_key(&id) {
+            if let Some(value) = self.binding.try_get(cx).cloned() {
+                cx.change_snapshots.insert(id, Box::new(value));
+            }
+        }
+
+        if let Some(lifecycle) = &mut cx.lifecycle {
+            let (binding, func) = (self.binding, self.func.clone());
+            let observer: LifecycleFn = Rc::new(move |cx: &mut Context| {
+                let new = match binding.try_get(cx) {
+                    Some(new) => new.clone(),
+                    None => return,
+                };
+                let old = cx.change_snapshots.insert(id, Box::new(new.clone()));
+                if let Some(old) = old.and_then(|old| old.downcast::<S>().ok()) {
+                    if *old != new {
+                        func(cx, &old, &new)
+                    }
+                }
+            });
+            lifecycle.change.push((id, observer));
+        }
+
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V, B, F, S> private::Sealed for ChangeView<V, B, F, S> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_on_change() {
+        let mut cx = Context::new();
+        let changes = Rc::new(RefCell::new(vec![]));
+        let log = changes.clone();
+        let ui = state(
+            || 0.0,
+            move |s, _| {
+                let log = log.clone();
+                knob(s).on_change(s, move |_, old: &f32, new: &f32| {
+                    log.borrow_mut().push((*old, *new))
+                })
+            },
+        );
+        let s = StateHandle::<f32>::new(cx.root_id);
+
+        // Joining the tree only takes a snapshot.
+        cx.gc(&ui);
+        cx.process(&ui, &Event::Anim);
+        assert!(changes.borrow().is_empty());
+
+        // A change from outside the view, as from `on_main`.
+        cx[s] = 0.5;
+        cx.process(&ui, &Event::Anim);
+        assert_eq!(*changes.borrow(), vec![(0.0, 0.5)]);
+
+        cx.process(&ui, &Event::Anim);
+        assert_eq!(changes.borrow().len(), 1);
+
+        cx[s] = 0.25;
+        cx[s] = 0.5;
+        cx.process(&ui, &Event::Anim);
+        assert_eq!(changes.borrow().len(), 1);
+
+        cx[s] = 1.0;
+        cx.process(&ui, &Event::Anim);
+        assert_eq!(*changes.borrow(), vec![(0.0, 0.5), (0.5, 1.0)]);
+    }
+
+    #[test]
+    fn test_on_change_tap() {
+        let mut cx = Context::new();
+        let changes = Rc::new(RefCell::new(vec![]));
+        let log = changes.clone();
+        let ui = state(
+            || 0,
+            move |s, _| {
+                let log = log.clone();
+                rectangle()
+                    .tap(move |cx| cx[s] += 1)
+                    .on_change(s, move |_, old: &i32, new: &i32| {
+                        log.borrow_mut().push((*old, *new))
+                    })
+            },
+        );
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: [100.0, 100.0].into(),
+                cx: &mut cx,
+                text_bounds: &mut |_, _, _| LocalRect::new(LocalPoint::zero(), [20.0, 10.0].into()),
+            },
+        );
+        cx.gc(&ui);
+
+        // The closure runs in the same `process` call as the tap.
+        let position = [50.0, 50.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        assert!(changes.borrow().is_empty());
+        cx.process(&ui, &Event::TouchEnd { id: 0, position });
+        assert_eq!(*changes.borrow(), vec![(0, 1)]);
+    }
+}
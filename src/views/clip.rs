@@ -54,6 +54,10 @@ where
         args.sz
     }
 
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
         let rect = self.geom(id, cx);
 
@@ -69,6 +73,10 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
         self.child.gc(id.child(&0), cx, map)
@@ -78,7 +86,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         self.child.access(id.child(&0), cx, nodes)
     }
@@ -1,11 +1,42 @@
 use crate::*;
 use std::any::Any;
 
+type CommandPredicate = Option<Box<dyn Fn(&Context) -> bool>>;
+type CommandTitle = Option<Box<dyn Fn(&Context) -> String>>;
+
+/// Dynamic state of a command, evaluated when commands are collected.
+#[derive(Default)]
+pub(crate) struct CommandOptions {
+    enabled: CommandPredicate,
+    checked: CommandPredicate,
+    title: CommandTitle,
+}
+
+impl CommandOptions {
+    fn is_enabled(&self, cx: &Context) -> bool {
+        match &self.enabled {
+            Some(f) => f(cx),
+            None => true,
+        }
+    }
+
+    fn info(&self, path: &str, key: Option<KeyChord>, cx: &Context) -> CommandInfo {
+        let mut info = CommandInfo::new(path, key);
+        if let Some(f) = &self.title {
+            info.title = f(cx);
+        }
+        info.enabled = self.is_enabled(cx);
+        info.checked = matches!(&self.checked, Some(f) if f(cx));
+        info
+    }
+}
+
 pub struct Command<V, F> {
     child: V,
     name: String,
-    key: Option<HotKey>,
+    key: Option<KeyChord>,
     func: F,
+    opts: CommandOptions,
 }
 
 impl<V, F> Command<V, F>
@@ -13,14 +44,34 @@ where
     V: View,
     F: Fn(&mut Context) + 'static,
 {
-    pub fn new(v: V, name: String, key: Option<HotKey>, f: F) -> Self {
+    pub fn new(v: V, name: String, key: Option<KeyChord>, f: F) -> Self {
         Self {
             child: v,
             name,
             key,
             func: f,
+            opts: CommandOptions::default(),
         }
     }
+
+    /// Only allows the command to run when `f` returns true.
+    /// Menus show disabled commands greyed out.
+    pub fn enabled(mut self, f: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.opts.enabled = Some(Box::new(f));
+        self
+    }
+
+    /// Shows a check mark next to the command in menus when `f` returns true.
+    pub fn checked(mut self, f: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.opts.checked = Some(Box::new(f));
+        self
+    }
+
+    /// Computes the title shown in menus, instead of the last part of the path.
+    pub fn title(mut self, f: impl Fn(&Context) -> String + 'static) -> Self {
+        self.opts.title = Some(Box::new(f));
+        self
+    }
 }
 
 impl<V, F> View for Command<V, F>
@@ -36,7 +87,7 @@ where
         actions: &mut Vec<Box<dyn Any>>,
     ) {
         if let Event::Command(name) = &event {
-            if *name == self.name {
+            if *name == self.name && self.opts.is_enabled(cx) {
                 (self.func)(cx);
             }
         }
@@ -51,16 +102,21 @@ where
         self.child.layout(id.child(&0), args)
     }
 
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
         self.child.hittest(id.child(&0), pt, cx)
     }
 
     fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
         self.child.commands(id.child(&0), cx, cmds);
-        cmds.push(CommandInfo {
-            path: self.name.clone(),
-            key: self.key,
-        })
+        cmds.push(self.opts.info(&self.name, self.key, cx))
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
     }
 
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
@@ -71,7 +127,7 @@ where
         &self,
         _id: ViewId,
         _cx: &mut Context,
-        _nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        _nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         // XXX: how does accesskit handle menu commands?
         None
@@ -88,15 +144,21 @@ where
 pub trait CommandBase {
     fn exec(&self);
     fn name(&self) -> String;
-    fn key(&self) -> Option<HotKey>;
+    fn key(&self) -> Option<KeyChord>;
+    fn is_enabled(&self, _cx: &Context) -> bool {
+        true
+    }
+    fn info(&self, _cx: &Context) -> CommandInfo {
+        CommandInfo::new(&self.name(), self.key())
+    }
 }
 
 pub trait CommandTuple {
     fn foreach_cmd<F: FnMut(&dyn CommandBase)>(&self, f: &mut F);
     fn len(&self) -> usize;
     fn is_empty(&self) -> bool {
-        false
-    } // satisfy clippy
+        self.len() == 0
+    }
 }
 
 impl<A: CommandBase> CommandTuple for (A,) {
@@ -257,7 +319,7 @@ where
     ) {
         if let Event::Command(name) = &event {
             self.cmds.foreach_cmd(&mut |cmd| {
-                if cmd.name() == *name {
+                if cmd.name() == *name && cmd.is_enabled(cx) {
                     cmd.exec();
                 }
             });
@@ -273,18 +335,21 @@ where
         self.child.layout(id.child(&0), args)
     }
 
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
         self.child.hittest(id.child(&0), pt, cx)
     }
 
     fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
         self.child.commands(id.child(&0), cx, cmds);
-        self.cmds.foreach_cmd(&mut |cmd| {
-            cmds.push(CommandInfo {
-                path: cmd.name(),
-                key: cmd.key(),
-            })
-        });
+        self.cmds.foreach_cmd(&mut |cmd| cmds.push(cmd.info(cx)));
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
     }
 
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
@@ -295,7 +360,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         self.child.access(id.child(&0), cx, nodes)
     }
@@ -305,7 +370,8 @@ impl<V, C> private::Sealed for CommandGroup<V, C> {}
 
 pub struct NullCommand {
     name: String,
-    key: Option<HotKey>,
+    key: Option<KeyChord>,
+    opts: CommandOptions,
 }
 
 /// Specifies a menu command.
@@ -313,6 +379,7 @@ pub fn command(name: &str) -> NullCommand {
     NullCommand {
         name: name.into(),
         key: None,
+        opts: CommandOptions::default(),
     }
 }
 
@@ -321,33 +388,56 @@ impl CommandBase for NullCommand {
     fn name(&self) -> String {
         self.name.clone()
     }
-    fn key(&self) -> Option<HotKey> {
-        None
+    fn key(&self) -> Option<KeyChord> {
+        self.key
+    }
+    fn is_enabled(&self, cx: &Context) -> bool {
+        self.opts.is_enabled(cx)
+    }
+    fn info(&self, cx: &Context) -> CommandInfo {
+        self.opts.info(&self.name, self.key, cx)
     }
 }
 
 impl NullCommand {
-    /// Adds a hotkey to the menu command.
-    pub fn hotkey(self, key: HotKey) -> Self {
+    /// Adds a hotkey to the menu command. Takes a `HotKey` or a `KeyChord`.
+    pub fn hotkey(self, key: impl Into<KeyChord>) -> Self {
         Self {
-            name: self.name,
-            key: Some(key),
+            key: Some(key.into()),
+            ..self
         }
     }
+    /// Only allows the command to run when `f` returns true.
+    pub fn enabled(mut self, f: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.opts.enabled = Some(Box::new(f));
+        self
+    }
+    /// Shows a check mark next to the command in menus when `f` returns true.
+    pub fn checked(mut self, f: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.opts.checked = Some(Box::new(f));
+        self
+    }
+    /// Computes the title shown in menus.
+    pub fn title(mut self, f: impl Fn(&Context) -> String + 'static) -> Self {
+        self.opts.title = Some(Box::new(f));
+        self
+    }
     /// Adds an action to the menu command.
     pub fn action<F: Fn()>(self, func: F) -> Command2<F> {
         Command2 {
             name: self.name,
             key: self.key,
             func,
+            opts: self.opts,
         }
     }
 }
 
 pub struct Command2<F: Fn()> {
     name: String,
-    key: Option<HotKey>,
+    key: Option<KeyChord>,
     func: F,
+    opts: CommandOptions,
 }
 
 impl<F> CommandBase for Command2<F>
@@ -360,21 +450,144 @@ where
     fn name(&self) -> String {
         self.name.clone()
     }
-    fn key(&self) -> Option<HotKey> {
+    fn key(&self) -> Option<KeyChord> {
         self.key
     }
+    fn is_enabled(&self, cx: &Context) -> bool {
+        self.opts.is_enabled(cx)
+    }
+    fn info(&self, cx: &Context) -> CommandInfo {
+        self.opts.info(&self.name, self.key, cx)
+    }
 }
 
 impl<F> Command2<F>
 where
     F: Fn(),
 {
-    /// Adds a hotkey to the menu command.
-    pub fn hotkey(self, key: HotKey) -> Self {
+    /// Adds a hotkey to the menu command. Takes a `HotKey` or a `KeyChord`.
+    pub fn hotkey(self, key: impl Into<KeyChord>) -> Self {
         Self {
-            name: self.name,
-            key: Some(key),
-            func: self.func,
+            key: Some(key.into()),
+            ..self
         }
     }
+    /// Only allows the command to run when `f` returns true.
+    pub fn enabled(mut self, f: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.opts.enabled = Some(Box::new(f));
+        self
+    }
+    /// Shows a check mark next to the command in menus when `f` returns true.
+    pub fn checked(mut self, f: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.opts.checked = Some(Box::new(f));
+        self
+    }
+    /// Computes the title shown in menus.
+    pub fn title(mut self, f: impl Fn(&Context) -> String + 'static) -> Self {
+        self.opts.title = Some(Box::new(f));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_command_hotkey() {
+        let mut cx = Context::new();
+
+        let ui = state(
+            || 0,
+            |count, _| {
+                rectangle()
+                    .command("File:New", Some(HotKey::KeyN.into()), move |cx| {
+                        cx[count] += 1
+                    })
+                    .command("File:Next", "Primary+N".parse().ok(), move |cx| {
+                        cx[count] += 10
+                    })
+            },
+        );
+        cx.update_commands(&ui);
+        assert_eq!(cx.commands.len(), 2);
+        assert_eq!(
+            cx.hotkey_conflicts(),
+            &[HotKeyConflict {
+                key: HotKey::KeyN.into(),
+                used: "File:New".into(),
+                ignored: "File:Next".into(),
+            }]
+        );
+
+        let mods = KeyboardModifiers {
+            command: cfg!(target_os = "macos"),
+            control: !cfg!(target_os = "macos"),
+            ..Default::default()
+        };
+        let s = StateHandle::<i32>::new(cx.root_id);
+
+        // The first binding wins.
+        cx.process(
+            &ui,
+            &Event::KeyDown {
+                key: Key::Character('n'),
+                mods,
+                repeat: false,
+            },
+        );
+        assert_eq!(cx[s], 1);
+
+        // No primary modifier, no command.
+        cx.process(
+            &ui,
+            &Event::KeyDown {
+                key: Key::Character('n'),
+                mods: Default::default(),
+                repeat: false,
+            },
+        );
+        assert_eq!(cx[s], 1);
+    }
+
+    #[test]
+    fn test_command_enabled() {
+        let mut cx = Context::new();
+
+        let ui = state(
+            || 0,
+            |count, cx| {
+                let n = cx[count];
+                rectangle()
+                    .command("Edit:Undo", None, move |cx| cx[count] += 1)
+                    .enabled(move |cx| cx[count] < 2)
+                    .checked(move |cx| cx[count] > 0)
+                    .title(move |cx| format!("Undo {}", cx[count]))
+                    .command("Edit:Redo", None, move |cx| cx[count] -= 1)
+                    .enabled(move |_| n > 100)
+            },
+        );
+        cx.update_commands(&ui);
+        let s = StateHandle::<i32>::new(cx.root_id);
+
+        assert_eq!(cx.commands[0].title, "Undo 0");
+        assert!(cx.commands[0].enabled && !cx.commands[0].checked);
+        assert_eq!(cx.commands[1].title, "Redo");
+        assert!(!cx.commands[1].enabled);
+
+        cx.run_command("Edit:Redo");
+        cx.process(&ui, &Event::Anim);
+        assert_eq!(cx[s], 0);
+
+        for _ in 0..3 {
+            cx.run_command("Edit:Undo");
+            cx.process(&ui, &Event::Anim);
+        }
+        assert_eq!(cx[s], 2);
+
+        cx.update_commands(&ui);
+        assert_eq!(cx.commands[0].title, "Undo 2");
+        assert!(!cx.commands[0].enabled && cx.commands[0].checked);
+    }
 }
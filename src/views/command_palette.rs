@@ -0,0 +1,636 @@
+use crate::*;
+use std::any::Any;
+
+const PALETTE_WIDTH: f32 = 400.0;
+const PALETTE_MARGIN: f32 = 20.0;
+const PALETTE_FILTER_HEIGHT: f32 = 30.0;
+const PALETTE_ROW_HEIGHT: f32 = 22.0;
+const PALETTE_PADDING: f32 = 10.0;
+const PALETTE_MAX_ROWS: usize = 10;
+
+/// Scores how well `pattern` matches `text` as a case-insensitive
+/// subsequence. Consecutive characters and word starts score higher.
+fn fuzzy_score(pattern: &str, text: &str) -> Option<i32> {
+    let mut score = 0;
+    let mut pattern = pattern.chars().map(|c| c.to_ascii_lowercase()).peekable();
+    let mut prev: Option<char> = None;
+    let mut prev_matched = false;
+
+    for c in text.chars() {
+        let want = match pattern.peek() {
+            Some(want) => *want,
+            None => break,
+        };
+        let matched = c.to_ascii_lowercase() == want;
+        if matched {
+            pattern.next();
+            score += 1;
+            if prev_matched {
+                score += 5;
+            }
+            if matches!(prev, None | Some(' ') | Some(':')) {
+                score += 3;
+            }
+        }
+        prev_matched = matched;
+        prev = Some(c);
+    }
+
+    if pattern.peek().is_some() {
+        None
+    } else {
+        Some(score)
+    }
+}
+
+/// The text shown for a command: its path with the evaluated title.
+fn command_label(cmd: &CommandInfo) -> String {
+    let mut parts: Vec<&str> = cmd.path.split(':').collect();
+    parts.pop();
+    parts.push(&cmd.title);
+    parts.join(": ")
+}
+
+/// Enabled commands matching `filter`, recently used first, then best matches.
+fn palette_matches<'a>(
+    cmds: &'a [CommandInfo],
+    filter: &str,
+    recent: &[String],
+) -> Vec<&'a CommandInfo> {
+    let mut matches: Vec<(usize, i32, &CommandInfo)> = cmds
+        .iter()
+        .filter(|cmd| cmd.enabled)
+        .filter_map(|cmd| {
+            let score = fuzzy_score(filter, &command_label(cmd))?;
+            let rank = recent
+                .iter()
+                .position(|path| *path == cmd.path)
+                .unwrap_or(usize::MAX);
+            Some((rank, score, cmd))
+        })
+        .collect();
+
+    // Stable, so equally good matches keep their order.
+    matches.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    matches.into_iter().map(|(_, _, cmd)| cmd).collect()
+}
+
+#[derive(Clone, Default)]
+struct PaletteState {
+    filter: String,
+    /// Index of the highlighted match.
+    selected: usize,
+    /// Command paths, most recently run first.
+    recent: Vec<String>,
+    /// Transform from the last draw, for placing the palette.
+    xform: LocalToWorld,
+    /// Theme from the last draw, for drawing the palette.
+    theme: Theme,
+}
+
+fn palette_state(cx: &mut Context, id: ViewId) -> &mut PaletteState {
+    cx.init_state(id, &PaletteState::default);
+    cx.state_map
+        .get_mut(&id)
+        .unwrap()
+        .state
+        .downcast_mut()
+        .unwrap()
+}
+
+/// Where the top edge of the palette goes, near the top of `rect`.
+fn palette_anchor(rect: &LocalRect) -> LocalRect {
+    let width = PALETTE_WIDTH
+        .min(rect.width() - 2.0 * PALETTE_MARGIN)
+        .max(0.0);
+    LocalRect::new(
+        [
+            rect.min_x() + (rect.width() - width) / 2.0,
+            rect.max_y() - PALETTE_MARGIN,
+        ]
+        .into(),
+        [width, 0.0].into(),
+    )
+}
+
+/// The highlighted match, which may be stale if the matches changed.
+fn clamp_selection(selected: usize, matches: usize) -> usize {
+    selected.min(matches.saturating_sub(1))
+}
+
+/// The first match shown, keeping the selection in view.
+fn first_row(selected: usize, matches: usize) -> usize {
+    (clamp_selection(selected, matches) + 1).saturating_sub(PALETTE_MAX_ROWS)
+}
+
+fn palette_row_rect(panel: &LocalRect, row: usize) -> LocalRect {
+    LocalRect::new(
+        [
+            panel.min_x(),
+            panel.max_y() - PALETTE_FILTER_HEIGHT - (row + 1) as f32 * PALETTE_ROW_HEIGHT,
+        ]
+        .into(),
+        [panel.width(), PALETTE_ROW_HEIGHT].into(),
+    )
+}
+
+/// Cmd+Shift+P on macOS, Ctrl+Shift+P elsewhere.
+fn is_palette_key(key: &Key, mods: &KeyboardModifiers) -> bool {
+    let mut chord = KeyChord::primary(Key::Character('p'));
+    chord.mods.shift = true;
+    chord.matches(key, mods)
+}
+
+/// Struct for the `command_palette` modifier.
+pub struct CommandPalette<V> {
+    child: V,
+}
+
+impl<V> CommandPalette<V>
+where
+    V: View,
+{
+    pub fn new(child: V) -> Self {
+        Self { child }
+    }
+
+    /// Shows the palette near the top of the view, with an empty filter.
+    fn open(&self, id: ViewId, cx: &mut Context) {
+        let rect = cx.layout.get(&id).map(|b| b.rect).unwrap_or_default();
+        let state = palette_state(cx, id);
+        state.filter.clear();
+        state.selected = 0;
+        let anchor = state.xform.outer_transformed_rect(&palette_anchor(&rect));
+        cx.present_overlay(id, anchor, PalettePanel { owner: id });
+    }
+}
+
+impl<V> View for CommandPalette<V>
+where
+    V: View,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        // Once open, the palette is an overlay and gets events first.
+        if let Event::KeyDown { key, mods, .. } = event {
+            if is_palette_key(key, mods) {
+                self.open(id, cx);
+                cx.set_handled();
+                return;
+            }
+        }
+
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        let theme = args.cx.theme();
+        let state = palette_state(args.cx, id);
+        state.xform = args.vger.current_transform();
+        state.theme = theme;
+        self.child.draw(id.child(&0), args);
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let sz = self.child.layout(id.child(&0), args);
+
+        args.cx.layout.insert(
+            id,
+            LayoutBox {
+                rect: LocalRect::new(LocalPoint::zero(), sz),
+                offset: LocalOffset::zero(),
+            },
+        );
+
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V> private::Sealed for CommandPalette<V> {}
+
+/// The open palette, presented as an overlay by `CommandPalette`.
+struct PalettePanel {
+    /// The `CommandPalette` which opened the panel, and holds its state.
+    owner: ViewId,
+}
+
+impl PalettePanel {
+    /// Closes the palette and sends the command.
+    fn run(&self, cx: &mut Context, path: &str) {
+        let recent = &mut palette_state(cx, self.owner).recent;
+        recent.retain(|p| p != path);
+        recent.insert(0, path.to_string());
+        cx.dismiss_overlay(self.owner);
+        cx.run_command(path);
+    }
+
+    fn edit_filter(&self, cx: &mut Context, f: impl FnOnce(&mut String)) {
+        let state = palette_state(cx, self.owner);
+        f(&mut state.filter);
+        state.selected = 0;
+        cx.set_dirty();
+    }
+
+    fn key(&self, cx: &mut Context, key: &Key, mods: &KeyboardModifiers) {
+        let state = palette_state(cx, self.owner).clone();
+        let matches: Vec<String> = palette_matches(&cx.commands, &state.filter, &state.recent)
+            .iter()
+            .map(|cmd| cmd.path.clone())
+            .collect();
+        let n = matches.len().max(1);
+
+        match key {
+            Key::Escape => cx.dismiss_overlay(self.owner),
+            _ if is_palette_key(key, mods) => cx.dismiss_overlay(self.owner),
+            Key::Enter => {
+                if let Some(path) = matches.get(clamp_selection(state.selected, n)) {
+                    self.run(cx, path);
+                }
+            }
+            Key::ArrowDown | Key::ArrowUp => {
+                let selected = clamp_selection(state.selected, n);
+                let selected = if *key == Key::ArrowDown {
+                    (selected + 1) % n
+                } else {
+                    (selected + n - 1) % n
+                };
+                palette_state(cx, self.owner).selected = selected;
+                cx.set_dirty();
+            }
+            Key::Backspace => self.edit_filter(cx, |filter| {
+                filter.pop();
+            }),
+            _ => (),
+        }
+    }
+}
+
+impl View for PalettePanel {
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        _actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        match event {
+            Event::KeyDown { key, mods, .. } => {
+                self.key(cx, key, mods);
+                cx.set_handled();
+            }
+            Event::TextInput(text) | Event::ImeCommit(text) => {
+                let text: String = text.chars().filter(|c| !c.is_control()).collect();
+                self.edit_filter(cx, |filter| filter.push_str(&text));
+                cx.set_handled();
+            }
+            Event::TouchBegin { position, .. } => {
+                let state = palette_state(cx, self.owner).clone();
+                let matches = palette_matches(&cx.commands, &state.filter, &state.recent);
+                let panel = cx.layout.get(&id).map(|b| b.rect).unwrap_or_default();
+                let first = first_row(state.selected, matches.len());
+                let row = (0..matches.len().min(PALETTE_MAX_ROWS))
+                    .find(|row| palette_row_rect(&panel, *row).contains(*position));
+                if let Some(cmd) = row.and_then(|row| matches.get(first + row)) {
+                    let path = cmd.path.clone();
+                    self.run(cx, &path);
+                }
+            }
+            _ if event.is_keyboard() => cx.set_handled(),
+            _ => (),
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        let state = palette_state(args.cx, self.owner).clone();
+        let matches = palette_matches(&args.cx.commands, &state.filter, &state.recent);
+        let panel = args.cx.layout[&id].rect;
+        let selected = clamp_selection(state.selected, matches.len());
+        let first = first_row(selected, matches.len());
+        let theme = state.theme;
+        let vger = &mut args.vger;
+
+        let paint = vger.color_paint(theme.button_color);
+        vger.fill_rect(panel, 0.0, paint);
+
+        // The filter, with a caret after the text.
+        let filter_rect = LocalRect::new(
+            [panel.min_x(), panel.max_y() - PALETTE_FILTER_HEIGHT].into(),
+            [panel.width(), PALETTE_FILTER_HEIGHT].into(),
+        );
+        let x = filter_rect.min_x() + PALETTE_PADDING;
+        if state.filter.is_empty() {
+            let color = theme.disabled_text_color;
+            draw_label(vger, "Type a command", x, &filter_rect, color);
+        } else {
+            draw_label(vger, &state.filter, x, &filter_rect, theme.text_color);
+        }
+        let w = if state.filter.is_empty() {
+            0.0
+        } else {
+            vger.text_bounds(&state.filter, MENU_FONT_SIZE, None)
+                .width()
+        };
+        let paint = vger.color_paint(theme.text_color);
+        let caret = LocalRect::new(
+            [x + w, filter_rect.min_y() + 7.0].into(),
+            [1.0, filter_rect.height() - 14.0].into(),
+        );
+        vger.fill_rect(caret, 0.0, paint);
+
+        for (row, cmd) in matches
+            .iter()
+            .skip(first)
+            .take(PALETTE_MAX_ROWS)
+            .enumerate()
+        {
+            let rect = palette_row_rect(&panel, row);
+            if first + row == selected {
+                let paint = vger.color_paint(theme.accent_background);
+                vger.fill_rect(rect, 0.0, paint);
+            }
+            let label = command_label(cmd);
+            draw_label(
+                vger,
+                &label,
+                rect.min_x() + PALETTE_PADDING,
+                &rect,
+                theme.text_color,
+            );
+
+            if let Some(key) = cmd.key {
+                let key = key.to_string();
+                let w = vger.text_bounds(&key, MENU_FONT_SIZE, None).width();
+                let x = rect.max_x() - PALETTE_PADDING - w;
+                draw_label(vger, &key, x, &rect, theme.disabled_text_color);
+            }
+        }
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let rect = args.cx.layout.get(&self.owner).map(|b| b.rect);
+        let state = palette_state(args.cx, self.owner).clone();
+        let matches = palette_matches(&args.cx.commands, &state.filter, &state.recent);
+        let rows = matches.len().min(PALETTE_MAX_ROWS);
+        let width = palette_anchor(&rect.unwrap_or_default()).width();
+        let sz = LocalSize::new(
+            width,
+            PALETTE_FILTER_HEIGHT + rows as f32 * PALETTE_ROW_HEIGHT,
+        );
+
+        args.cx.layout.insert(
+            id,
+            LayoutBox {
+                rect: LocalRect::new(LocalPoint::zero(), sz),
+                offset: LocalOffset::zero(),
+            },
+        );
+        sz
+    }
+
+    fn gc(&self, id: ViewId, _cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+    }
+}
+
+impl private::Sealed for PalettePanel {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("sav", "File: Save As").is_some());
+        assert!(fuzzy_score("fsa", "File: Save As").is_some());
+        assert!(fuzzy_score("xyz", "File: Save As").is_none());
+        assert_eq!(fuzzy_score("", "File: New"), Some(0));
+
+        // Consecutive matches beat scattered ones.
+        let a = fuzzy_score("new", "File: New").unwrap();
+        let b = fuzzy_score("new", "Edit: Next Word").unwrap();
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_command_palette() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, _| {
+                rectangle()
+                    .command("File:New", None, move |cx| cx[count] += 1)
+                    .command("File:Open", None, move |cx| cx[count] += 10)
+                    .command("Edit:Undo", None, move |cx| cx[count] += 100)
+                    .command_palette()
+            },
+        );
+        cx.update_commands(&ui);
+        let s = StateHandle::<i32>::new(cx.root_id);
+
+        let key_down = |key, mods| Event::KeyDown {
+            key,
+            mods,
+            repeat: false,
+        };
+        let mut chord = KeyChord::primary(Key::Character('p'));
+        chord.mods.shift = true;
+        let open = key_down(Key::Character('P'), chord.mods);
+
+        // Filter down to Open and run it.
+        cx.process(&ui, &open);
+        cx.process(&ui, &Event::TextInput("op".into()));
+        cx.process(&ui, &key_down(Key::Enter, Default::default()));
+        assert_eq!(cx[s], 10);
+
+        // Keys go to the app again once the palette closes.
+        cx.process(&ui, &key_down(Key::Enter, Default::default()));
+        assert_eq!(cx[s], 10);
+
+        // Recently used commands come first.
+        cx.process(&ui, &open);
+        cx.process(&ui, &key_down(Key::Enter, Default::default()));
+        assert_eq!(cx[s], 20);
+
+        cx.process(&ui, &open);
+        cx.process(&ui, &key_down(Key::ArrowDown, Default::default()));
+        cx.process(&ui, &key_down(Key::Enter, Default::default()));
+        assert_eq!(cx[s], 21);
+
+        // Escape closes without running anything.
+        cx.process(&ui, &open);
+        cx.process(&ui, &key_down(Key::Escape, Default::default()));
+        cx.process(&ui, &key_down(Key::Enter, Default::default()));
+        assert_eq!(cx[s], 21);
+
+        // Commands run elsewhere aren't recent.
+        cx.process(&ui, &Event::Command("Edit:Undo".into()));
+        cx.process(&ui, &open);
+        cx.process(&ui, &key_down(Key::Enter, Default::default()));
+        assert_eq!(cx[s], 122);
+    }
+
+    #[test]
+    fn test_command_palette_before_layout() {
+        let mut cx = Context::new();
+        let ui = rectangle()
+            .command("File:New", None, |_| ())
+            .command_palette();
+        cx.update_commands(&ui);
+
+        let mut chord = KeyChord::primary(Key::Character('p'));
+        chord.mods.shift = true;
+        cx.process(
+            &ui,
+            &Event::KeyDown {
+                key: Key::Character('P'),
+                mods: chord.mods,
+                repeat: false,
+            },
+        );
+        let position = [10.0, 10.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+    }
+
+    #[test]
+    fn test_command_palette_touch() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, _| {
+                rectangle()
+                    .command("File:New", None, move |cx| cx[count] += 1)
+                    .command("File:Open", None, move |cx| cx[count] += 10)
+                    .command_palette()
+            },
+        );
+        cx.update_commands(&ui);
+        let s = StateHandle::<i32>::new(cx.root_id);
+
+        let sz = [200.0, 200.0].into();
+        let mut text_bounds = |_: &str, _, _| LocalRect::zero();
+        let mut layout = |cx: &mut Context| {
+            ui.layout(
+                cx.root_id,
+                &mut LayoutArgs {
+                    sz,
+                    cx,
+                    text_bounds: &mut text_bounds,
+                },
+            );
+            cx.layout_overlays(sz, &mut text_bounds);
+        };
+        layout(&mut cx);
+
+        let mut chord = KeyChord::primary(Key::Character('p'));
+        chord.mods.shift = true;
+        cx.process(
+            &ui,
+            &Event::KeyDown {
+                key: Key::Character('P'),
+                mods: chord.mods,
+                repeat: false,
+            },
+        );
+        layout(&mut cx);
+
+        // The panel hangs from near the top of the view, over it.
+        assert_eq!(
+            cx.overlays[0].rect,
+            WorldRect::new([20.0, 106.0].into(), [160.0, 74.0].into())
+        );
+
+        let position = [100.0, 140.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        assert_eq!(cx[s], 1);
+        assert!(cx.overlays.is_empty());
+    }
+
+    #[test]
+    fn test_command_palette_narrowed() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, _| {
+                list((0..12).collect(), move |i: &i32| {
+                    let i = *i;
+                    rectangle().command(&format!("Item:{}", i), None, move |cx| cx[count] = i)
+                })
+                .command_palette()
+            },
+        );
+        cx.update_commands(&ui);
+        let s = StateHandle::<i32>::new(cx.root_id);
+        let palette = cx.root_id.child(&0);
+
+        let sz = [200.0, 200.0].into();
+        let mut text_bounds = |_: &str, _, _| LocalRect::zero();
+        let mut layout = |cx: &mut Context| {
+            ui.layout(
+                cx.root_id,
+                &mut LayoutArgs {
+                    sz,
+                    cx,
+                    text_bounds: &mut text_bounds,
+                },
+            );
+            cx.layout_overlays(sz, &mut text_bounds);
+        };
+        layout(&mut cx);
+
+        let key_down = |key, mods| Event::KeyDown {
+            key,
+            mods,
+            repeat: false,
+        };
+        let mut chord = KeyChord::primary(Key::Character('p'));
+        chord.mods.shift = true;
+        cx.process(&ui, &key_down(Key::Character('P'), chord.mods));
+
+        // Narrow to Item: 1, 10 and 11, with the selection left over
+        // from the longer list.
+        cx.process(&ui, &Event::TextInput("1".into()));
+        palette_state(&mut cx, palette).selected = 11;
+        layout(&mut cx);
+
+        // The second row is Item: 10.
+        let position = [100.0, 117.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        assert_eq!(cx[s], 10);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::*;
+use std::any::Any;
+use std::marker::PhantomData;
+
+/// Binding to a value computed from another binding, such as decibels
+/// over a linear gain. Create one with `computed`.
+///
+/// Reading runs the getter. `with_mut` runs the setter right away,
+/// while changes through `get_mut` are written back once the event has
+/// been processed.
+pub struct Computed<B, G, F, T> {
+    source: B,
+    get: G,
+    set: F,
+    cache: ViewId,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<B: Copy, G: Copy, F: Copy, T> Clone for Computed<B, G, F, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B: Copy, G: Copy, F: Copy, T> Copy for Computed<B, G, F, T> {}
+
+impl<S, B, G, F, T> Computed<B, G, F, T>
+where
+    S: Clone + 'static,
+    B: Binding<T>,
+    G: Fn(&T) -> S + Copy + 'static,
+    F: Fn(&mut T, S) + Copy + 'static,
+    T: 'static,
+{
+    fn cache(&self) -> StateHandle<S> {
+        StateHandle::new(self.cache)
+    }
+
+    /// Recomputes the cached value from the source.
+    fn refresh(&self, cx: &mut Context) {
+        if let Some(value) = self.source.try_with(cx, self.get) {
+            cx.set_state(self.cache, value);
+        }
+    }
+
+    /// Writes the cached value back if it was changed through `get_mut`.
+    fn write_back(&self, cx: &mut Context) {
+        if cx.is_dirty(self.cache) {
+            let value = cx.get(self.cache()).clone();
+            self.source.try_with_mut(cx, |t| (self.set)(t, value));
+            self.refresh(cx);
+        }
+    }
+}
+
+impl<S, B, G, F, T> Binding<S> for Computed<B, G, F, T>
+where
+    S: Clone + 'static,
+    B: Binding<T>,
+    G: Fn(&T) -> S + Copy + 'static,
+    F: Fn(&mut T, S) + Copy + 'static,
+    T: 'static,
+{
+    fn get<'a>(&self, cx: &'a Context) -> &'a S {
+        cx.get(self.cache())
+    }
+
+    fn get_mut<'a>(&self, cx: &'a mut Context) -> &'a mut S {
+        cx.get_mut(self.cache())
+    }
+
+    fn with<R>(&self, cx: &Context, f: impl FnOnce(&S) -> R) -> R {
+        f(&self.source.with(cx, self.get))
+    }
+
+    fn with_mut<R>(&self, cx: &mut Context, f: impl FnOnce(&mut S) -> R) -> R {
+        let mut value = self.source.with(cx, self.get);
+        let result = f(&mut value);
+        self.source.with_mut(cx, |t| (self.set)(t, value));
+        self.refresh(cx);
+        result
+    }
+
+    fn try_get<'a>(&self, cx: &'a Context) -> Option<&'a S> {
+        self.source.try_get(cx)?;
+        Some(self.get(cx))
+    }
+
+    fn try_get_mut<'a>(&self, cx: &'a mut Context) -> Option<&'a mut S> {
+        self.source.try_get(cx)?;
+        Some(self.get_mut(cx))
+    }
+
+    fn try_with<R>(&self, cx: &Context, f: impl FnOnce(&S) -> R) -> Option<R> {
+        Some(f(&self.source.try_with(cx, self.get)?))
+    }
+
+    fn try_with_mut<R>(&self, cx: &mut Context, f: impl FnOnce(&mut S) -> R) -> Option<R> {
+        let mut value = self.source.try_with(cx, self.get)?;
+        let result = f(&mut value);
+        self.source.try_with_mut(cx, |t| (self.set)(t, value));
+        self.refresh(cx);
+        Some(result)
+    }
+}
+
+struct ComputedView<B, G, F, T, VF> {
+    binding: Computed<B, G, F, T>,
+    func: VF,
+}
+
+impl<S, V, B, G, F, T, VF> ComputedView<B, G, F, T, VF>
+where
+    S: Clone + 'static,
+    V: View,
+    B: Binding<T>,
+    G: Fn(&T) -> S + Copy + 'static,
+    F: Fn(&mut T, S) + Copy + 'static,
+    T: 'static,
+    VF: Fn(Computed<B, G, F, T>, &Context) -> V + 'static,
+{
+    /// Refreshes the cache and builds the child view.
+    fn body(&self, id: ViewId, cx: &mut Context) -> V {
+        let binding = Computed {
+            cache: id,
+            ..self.binding
+        };
+        binding.refresh(cx);
+        (self.func)(binding, cx)
+    }
+}
+
+impl<S, V, B, G, F, T, VF> View for ComputedView<B, G, F, T, VF>
+where
+    S: Clone + 'static,
+    V: View,
+    B: Binding<T>,
+    G: Fn(&T) -> S + Copy + 'static,
+    F: Fn(&mut T, S) + Copy + 'static,
+    T: 'static,
+    VF: Fn(Computed<B, G, F, T>, &Context) -> V + 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        self.body(id, cx).process(event, id.child(&0), cx, actions);
+        Computed {
+            cache: id,
+            ..self.binding
+        }
+        .write_back(cx);
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.body(id, args.cx).draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.body(id, args.cx).layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.body(id, cx).dirty(id.child(&0), xform, cx)
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.body(id, cx).hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.body(id, cx).commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.body(id, cx).focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.body(id, cx).gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.body(id, cx).access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<B, G, F, T, VF> private::Sealed for ComputedView<B, G, F, T, VF> {}
+
+/// Passes `f` a binding to a value computed from `source`, which
+/// controls accept like any other binding. `get` computes the value and
+/// `set` writes a new value back into the source.
+///
+/// ```ignore
+/// computed(
+///     bind(s, Mixer::gain()),
+///     |gain| 20.0 * gain.log10(),
+///     |gain, db| *gain = 10.0f32.powf(db / 20.0),
+///     |db, cx| format!("{:.1} dB", db.get(cx)),
+/// )
+/// ```
+pub fn computed<S, V, T, B, G, F, VF>(source: B, get: G, set: F, f: VF) -> impl View
+where
+    S: Clone + 'static,
+    V: View,
+    B: Binding<T>,
+    G: Fn(&T) -> S + Copy + 'static,
+    F: Fn(&mut T, S) + Copy + 'static,
+    T: 'static,
+    VF: Fn(Computed<B, G, F, T>, &Context) -> V + 'static,
+{
+    ComputedView {
+        binding: Computed {
+            source,
+            get,
+            set,
+            cache: ViewId::default(),
+            phantom: PhantomData,
+        },
+        func: f,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use accesskit::{Action, ActionData, ActionRequest, Role};
+
+    #[derive(Lens)]
+    struct Mixer {
+        gain: f32,
+    }
+
+    /// Slider position for a gain, over -60 to 0 dB.
+    fn level(gain: &f32) -> f32 {
+        (20.0 * gain.log10() + 60.0) / 60.0
+    }
+
+    fn set_level(gain: &mut f32, level: f32) {
+        *gain = 10.0f32.powf((level * 60.0 - 60.0) / 20.0)
+    }
+
+    #[test]
+    fn test_computed() {
+        let mut cx = Context::new();
+        let ui = state(
+            || Mixer { gain: 1.0 },
+            |s, _| {
+                computed(bind(s, Mixer::gain()), level, set_level, |level, _| {
+                    vstack((
+                        hslider(level),
+                        button("min", move |cx| *level.get_mut(cx) = 0.0),
+                    ))
+                })
+            },
+        );
+        let s = StateHandle::<Mixer>::new(cx.root_id);
+
+        let update = cx.access_update(&ui).unwrap();
+        let find = |role| {
+            update
+                .nodes
+                .iter()
+                .find(|(_, node)| node.role() == role)
+                .unwrap()
+                .clone()
+        };
+        let (slider, slider_node) = find(Role::Slider);
+        let (button, _) = find(Role::Button);
+        assert_eq!(slider_node.numeric_value(), Some(1.0));
+
+        // with_mut runs the setter, which dirties the source.
+        cx.clear_dirty();
+        cx.process(
+            &ui,
+            &Event::Access(ActionRequest {
+                action: Action::SetValue,
+                target: slider,
+                data: Some(ActionData::NumericValue(0.5)),
+            }),
+        );
+        assert!((cx[s].gain - 10.0f32.powf(-1.5)).abs() < 1e-6);
+        assert!(cx.is_dirty(s.id()));
+
+        // Changes through get_mut are written back after processing.
+        cx.process(
+            &ui,
+            &Event::Access(ActionRequest {
+                action: Action::Default,
+                target: button,
+                data: None,
+            }),
+        );
+        assert!((cx[s].gain - 0.001).abs() < 1e-6);
+
+        let mut nodes = vec![];
+        ui.access(cx.root_id, &mut cx, &mut nodes);
+        let (_, slider_node) = nodes
+            .iter()
+            .find(|(_, node)| node.role() == Role::Slider)
+            .unwrap();
+        assert_eq!(slider_node.numeric_value(), Some(0.0));
+    }
+}
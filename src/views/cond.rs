@@ -43,6 +43,14 @@ where
         }
     }
 
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        if self.cond {
+            self.if_true.dirty(id.child(&0), xform, cx)
+        } else {
+            self.if_false.dirty(id.child(&1), xform, cx)
+        }
+    }
+
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
         if self.cond {
             self.if_true.hittest(id.child(&0), pt, cx)
@@ -59,6 +67,14 @@ where
         }
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        if self.cond {
+            self.if_true.focus_chain(id.child(&0), cx, chain)
+        } else {
+            self.if_false.focus_chain(id.child(&1), cx, chain)
+        }
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         if self.cond {
             self.if_true.gc(id.child(&0), cx, map)
@@ -71,7 +87,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         if self.cond {
             self.if_true.access(id.child(&0), cx, nodes)
@@ -0,0 +1,480 @@
+use crate::*;
+use std::any::Any;
+use std::rc::Rc;
+
+/// How long a touch must be held to open the menu, in seconds.
+const LONG_PRESS_TIME: f32 = 0.5;
+
+/// How far a touch can move before it no longer counts as a long press.
+const LONG_PRESS_SLOP: f32 = 10.0;
+
+#[derive(Clone, Default)]
+struct ContextMenuState {
+    /// Highlighted row.
+    selected: Option<usize>,
+
+    /// A touch which may become a long press: where it started
+    /// and how long it's been held.
+    press: Option<(LocalPoint, f32)>,
+
+    /// Transform from the last draw, for placing the menu.
+    xform: LocalToWorld,
+
+    /// Theme from the last draw, for drawing the menu.
+    theme: Theme,
+}
+
+fn context_menu_state(cx: &mut Context, id: ViewId) -> &mut ContextMenuState {
+    cx.init_state(id, &ContextMenuState::default);
+    cx.state_map
+        .get_mut(&id)
+        .unwrap()
+        .state
+        .downcast_mut()
+        .unwrap()
+}
+
+fn command_infos<C: CommandTuple>(cmds: &C, cx: &Context) -> Vec<CommandInfo> {
+    let mut infos = vec![];
+    cmds.foreach_cmd(&mut |cmd| infos.push(cmd.info(cx)));
+    infos
+}
+
+/// Struct for the `context_menu` modifier.
+pub struct ContextMenu<V, C> {
+    child: V,
+    cmds: Rc<C>,
+}
+
+impl<V, C> ContextMenu<V, C>
+where
+    V: View,
+    C: CommandTuple + 'static,
+{
+    pub fn new(child: V, cmds: C) -> Self {
+        Self {
+            child,
+            cmds: Rc::new(cmds),
+        }
+    }
+
+    /// Shows the menu hanging below `pt`, unless it would be empty.
+    fn open(&self, id: ViewId, cx: &mut Context, pt: LocalPoint) {
+        if self.cmds.is_empty() {
+            return;
+        }
+        let state = context_menu_state(cx, id);
+        state.selected = None;
+        state.press = None;
+        let anchor = WorldRect::new(state.xform.transform_point(pt), [0.0, 0.0].into());
+
+        let menu = ContextMenuPopup {
+            owner: id,
+            cmds: self.cmds.clone(),
+        };
+        cx.present_overlay(id, anchor, menu);
+    }
+}
+
+impl<V, C> View for ContextMenu<V, C>
+where
+    V: View,
+    C: CommandTuple + 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        match event {
+            Event::TouchBegin { position, .. }
+                if self.child.hittest(id.child(&0), *position, cx).is_some() =>
+            {
+                match cx.mouse_button {
+                    Some(MouseButton::Right) => {
+                        self.open(id, cx, *position);
+                        return;
+                    }
+                    // No mouse button means a touch screen.
+                    None => context_menu_state(cx, id).press = Some((*position, 0.0)),
+                    _ => (),
+                }
+            }
+            Event::TouchMove { position, .. } => {
+                let state = context_menu_state(cx, id);
+                if let Some((start, _)) = state.press {
+                    if (*position - start).length() > LONG_PRESS_SLOP {
+                        state.press = None;
+                    }
+                }
+            }
+            Event::TouchEnd { .. } => context_menu_state(cx, id).press = None,
+            Event::Anim => {
+                let state = context_menu_state(cx, id);
+                if let Some((start, t)) = &mut state.press {
+                    *t += 1.0 / 60.0; // Assume 60fps, like anim.
+                    if *t >= LONG_PRESS_TIME {
+                        let start = *start;
+                        self.open(id, cx, start);
+                    } else {
+                        // Keep frames coming until the press is long enough.
+                        cx.set_dirty();
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        let theme = args.cx.theme();
+        let state = context_menu_state(args.cx, id);
+        state.xform = args.vger.current_transform();
+        state.theme = theme;
+        self.child.draw(id.child(&0), args);
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V, C> private::Sealed for ContextMenu<V, C> {}
+
+/// The open menu, presented as an overlay by `ContextMenu`.
+struct ContextMenuPopup<C> {
+    /// The `ContextMenu` which opened the menu, and holds its state.
+    owner: ViewId,
+    cmds: Rc<C>,
+}
+
+impl<C> ContextMenuPopup<C>
+where
+    C: CommandTuple,
+{
+    fn set_selected(&self, cx: &mut Context, selected: Option<usize>) {
+        let state = context_menu_state(cx, self.owner);
+        if state.selected != selected {
+            state.selected = selected;
+            cx.set_dirty();
+        }
+    }
+
+    /// Runs the command for a row, if it's enabled, and closes the menu.
+    fn pick(&self, cx: &mut Context, row: usize) {
+        match command_infos(&*self.cmds, cx).get(row) {
+            Some(info) if info.enabled => (),
+            _ => return,
+        }
+        let mut i = 0;
+        self.cmds.foreach_cmd(&mut |cmd| {
+            if i == row {
+                cmd.exec();
+            }
+            i += 1;
+        });
+        cx.dismiss_overlay(self.owner);
+    }
+
+    /// The row under a point, if it's over the menu.
+    fn row_at(&self, id: ViewId, cx: &Context, pt: LocalPoint) -> Option<usize> {
+        let rect = cx.layout.get(&id)?.rect;
+        if rect.contains(pt) {
+            Some(((rect.max_y() - pt.y) / MENU_ROW_HEIGHT) as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<C> View for ContextMenuPopup<C>
+where
+    C: CommandTuple + 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        _actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        match event {
+            Event::TouchBegin { position, .. } => {
+                if let Some(row) = self.row_at(id, cx, *position) {
+                    self.pick(cx, row);
+                }
+            }
+            Event::TouchMove { position, .. } => {
+                let row = self.row_at(id, cx, *position);
+                self.set_selected(cx, row);
+            }
+            Event::KeyDown { key, .. } => {
+                let n = self.cmds.len();
+                let selected = context_menu_state(cx, self.owner).selected;
+                match key {
+                    Key::Escape => cx.dismiss_overlay(self.owner),
+                    // Nothing to select without commands.
+                    Key::ArrowDown | Key::ArrowUp if n == 0 => (),
+                    Key::ArrowDown => {
+                        let row = selected.map_or(0, |i| (i + 1) % n);
+                        self.set_selected(cx, Some(row))
+                    }
+                    Key::ArrowUp => {
+                        let row = selected.map_or(n - 1, |i| (i + n - 1) % n);
+                        self.set_selected(cx, Some(row))
+                    }
+                    Key::Enter | Key::Space => {
+                        if let Some(row) = selected {
+                            self.pick(cx, row);
+                        }
+                    }
+                    _ => (),
+                }
+                cx.set_handled();
+            }
+            _ if event.is_keyboard() => cx.set_handled(),
+            _ => (),
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        let rect = args.cx.layout[&id].rect;
+        let state = context_menu_state(args.cx, self.owner).clone();
+        let infos = command_infos(&*self.cmds, args.cx);
+        let vger = &mut args.vger;
+        let paint = vger.color_paint(state.theme.button_color);
+        vger.fill_rect(rect, 0.0, paint);
+
+        for (row, info) in infos.iter().enumerate() {
+            let rect = menu_row_rect(&rect, row);
+            if state.selected == Some(row) {
+                let paint = vger.color_paint(state.theme.accent_background);
+                vger.fill_rect(rect, 0.0, paint);
+            }
+            draw_menu_row(vger, &rect, &MenuItem::new(info), &state.theme);
+        }
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let infos = command_infos(&*self.cmds, args.cx);
+        let width = infos
+            .iter()
+            .map(|info| menu_row_width(args, &MenuItem::new(info)))
+            .fold(0.0, f32::max);
+        let sz = LocalSize::new(width, infos.len() as f32 * MENU_ROW_HEIGHT);
+
+        args.cx.layout.insert(
+            id,
+            LayoutBox {
+                rect: LocalRect::new(LocalPoint::zero(), sz),
+                offset: LocalOffset::zero(),
+            },
+        );
+        sz
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.row_at(id, cx, pt).map(|_| id)
+    }
+
+    fn gc(&self, id: ViewId, _cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+    }
+}
+
+impl<C> private::Sealed for ContextMenuPopup<C> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::Cell;
+
+    fn layout(ui: &impl View, cx: &mut Context) {
+        let sz = [200.0, 200.0].into();
+        let mut text_bounds =
+            |_: &str, _, _| LocalRect::new(LocalPoint::zero(), [50.0, 10.0].into());
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz,
+                cx,
+                text_bounds: &mut text_bounds,
+            },
+        );
+        cx.layout_overlays(sz, &mut text_bounds);
+    }
+
+    fn touch(cx: &mut Context, ui: &impl View, position: LocalPoint) {
+        cx.process(ui, &Event::TouchBegin { id: 0, position });
+        cx.process(ui, &Event::TouchEnd { id: 0, position });
+    }
+
+    #[test]
+    fn test_context_menu() {
+        let mut cx = Context::new();
+        let copied = Rc::new(Cell::new(0));
+        let pasted = Rc::new(Cell::new(0));
+
+        let ui = {
+            let copied = copied.clone();
+            let pasted = pasted.clone();
+            rectangle().context_menu((
+                command("Copy").action(move || copied.set(copied.get() + 1)),
+                command("Paste")
+                    .enabled(|_| false)
+                    .action(move || pasted.set(pasted.get() + 1)),
+            ))
+        };
+        layout(&ui, &mut cx);
+
+        // Left clicks don't open the menu.
+        cx.mouse_button = Some(MouseButton::Left);
+        touch(&mut cx, &ui, [100.0, 100.0].into());
+        let root = cx.root_id;
+        assert!(!cx.is_overlay_presented(root));
+
+        // Right click opens the menu below the pointer.
+        cx.mouse_button = Some(MouseButton::Right);
+        touch(&mut cx, &ui, [100.0, 100.0].into());
+        cx.mouse_button = None;
+        layout(&ui, &mut cx);
+        assert_eq!(
+            cx.overlays[0].rect,
+            WorldRect::new([100.0, 56.0].into(), [70.0, 44.0].into())
+        );
+
+        // Disabled rows do nothing.
+        touch(&mut cx, &ui, [110.0, 70.0].into());
+        assert_eq!(pasted.get(), 0);
+
+        touch(&mut cx, &ui, [110.0, 95.0].into());
+        assert_eq!(copied.get(), 1);
+
+        // Clicking outside dismisses the menu.
+        cx.mouse_button = Some(MouseButton::Right);
+        touch(&mut cx, &ui, [100.0, 100.0].into());
+        cx.mouse_button = None;
+        layout(&ui, &mut cx);
+        touch(&mut cx, &ui, [10.0, 10.0].into());
+        touch(&mut cx, &ui, [110.0, 95.0].into());
+        assert_eq!(copied.get(), 1);
+    }
+
+    #[test]
+    fn test_context_menu_long_press() {
+        let mut cx = Context::new();
+        let copied = Rc::new(Cell::new(0));
+
+        let ui = {
+            let copied = copied.clone();
+            rectangle()
+                .context_menu((command("Copy").action(move || copied.set(copied.get() + 1)),))
+        };
+        layout(&ui, &mut cx);
+        let root = cx.root_id;
+
+        // A short touch doesn't open the menu.
+        touch(&mut cx, &ui, [100.0, 100.0].into());
+        cx.process(&ui, &Event::Anim);
+        assert!(!cx.is_overlay_presented(root));
+
+        let position = [100.0, 100.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        for _ in 0..60 {
+            cx.process(&ui, &Event::Anim);
+        }
+        cx.process(&ui, &Event::TouchEnd { id: 0, position });
+        assert!(cx.is_overlay_presented(root));
+
+        // Escape dismisses it.
+        let key_down = |key| Event::KeyDown {
+            key,
+            mods: Default::default(),
+            repeat: false,
+        };
+        cx.process(&ui, &key_down(Key::Escape));
+        assert!(!cx.is_overlay_presented(root));
+
+        // Keyboard selection.
+        cx.mouse_button = Some(MouseButton::Right);
+        touch(&mut cx, &ui, position);
+        cx.mouse_button = None;
+        cx.process(&ui, &key_down(Key::ArrowDown));
+        cx.process(&ui, &key_down(Key::Enter));
+        assert_eq!(copied.get(), 1);
+    }
+
+    struct NoCommands;
+
+    impl CommandTuple for NoCommands {
+        fn foreach_cmd<F: FnMut(&dyn CommandBase)>(&self, _f: &mut F) {}
+        fn len(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn test_context_menu_empty() {
+        let mut cx = Context::new();
+        let ui = rectangle().context_menu(NoCommands);
+        layout(&ui, &mut cx);
+        let root = cx.root_id;
+
+        // There's no menu to show.
+        cx.mouse_button = Some(MouseButton::Right);
+        touch(&mut cx, &ui, [100.0, 100.0].into());
+        cx.mouse_button = None;
+        assert!(!cx.is_overlay_presented(root));
+
+        // Nor anything to select if it were shown.
+        let menu = ContextMenuPopup {
+            owner: root,
+            cmds: Rc::new(NoCommands),
+        };
+        cx.present_overlay(root, WorldRect::zero(), menu);
+        for key in [Key::ArrowDown, Key::ArrowUp, Key::Enter] {
+            let event = Event::KeyDown {
+                key,
+                mods: Default::default(),
+                repeat: false,
+            };
+            cx.process(&ui, &event);
+        }
+        assert!(cx.is_overlay_presented(root));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::*;
+use std::any::Any;
+
+/// Trait for views defined outside rui. Wrap one with `custom` to use
+/// it as a `View`.
+///
+/// Every method has a default. When `child` returns a view, the defaults
+/// forward to it with the ID `id.child(&0)`, so a wrapper only implements
+/// what it changes. Otherwise the defaults draw nothing, take all the
+/// space offered and ignore events.
+///
+/// The rect from `layout` is stored for the view's ID, and can be read
+/// back with `Context::layout_rect`.
+pub trait CustomView: 'static {
+    /// The view this one wraps, if any.
+    fn child(&self) -> Option<&dyn View> {
+        None
+    }
+
+    /// Draws the view using vger.
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        if let Some(child) = self.child() {
+            child.draw(id.child(&0), args)
+        }
+    }
+
+    /// Lays out subviews and returns the size of the view.
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        match self.child() {
+            Some(child) => child.layout(id.child(&0), args),
+            None => args.sz,
+        }
+    }
+
+    /// Processes an event.
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        if let Some(child) = self.child() {
+            child.process(event, id.child(&0), cx, actions)
+        }
+    }
+
+    /// Determines dirty regions which need repainting.
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        if let Some(child) = self.child() {
+            child.dirty(id.child(&0), xform, cx)
+        }
+    }
+
+    /// Returns the topmost view which the point intersects.
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child()?.hittest(id.child(&0), pt, cx)
+    }
+
+    /// Accumulates information about menu bar commands.
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        if let Some(child) = self.child() {
+            child.commands(id.child(&0), cx, cmds)
+        }
+    }
+
+    /// Accumulates the IDs of views which can take keyboard focus.
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        if let Some(child) = self.child() {
+            child.focus_chain(id.child(&0), cx, chain)
+        }
+    }
+
+    /// Gets IDs for views currently in use. The view's own ID is
+    /// already included.
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        if let Some(child) = self.child() {
+            child.gc(id.child(&0), cx, map)
+        }
+    }
+
+    /// Builds an AccessKit tree, returning the node ID for the subtree.
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child()?.access(id.child(&0), cx, nodes)
+    }
+
+    /// For detecting flexible sized things in stacks.
+    fn is_flexible(&self) -> bool {
+        false
+    }
+}
+
+/// Struct for `custom`.
+pub struct Custom<T> {
+    view: T,
+}
+
+impl<T> View for Custom<T>
+where
+    T: CustomView,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        self.view.process(event, id, cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.view.draw(id, args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let sz = self.view.layout(id, args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.view.dirty(id, xform, cx)
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.view.hittest(id, pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.view.commands(id, cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.view.focus_chain(id, cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.view.gc(id, cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.view.access(id, cx, nodes)
+    }
+
+    fn is_flexible(&self) -> bool {
+        self.view.is_flexible()
+    }
+}
+
+impl<T> private::Sealed for Custom<T> {}
+
+/// Uses a `CustomView` as a `View`. See `examples/custom_view.rs`.
+pub fn custom<T: CustomView>(view: T) -> Custom<T> {
+    Custom { view }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Leaf view which is hit inside its left half.
+    struct LeftHalf;
+
+    impl CustomView for LeftHalf {
+        fn layout(&self, _id: ViewId, _args: &mut LayoutArgs) -> LocalSize {
+            [40.0, 20.0].into()
+        }
+
+        fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+            let rect = cx.layout_rect(id);
+            if pt.x < rect.width() / 2.0 && rect.contains(pt) {
+                Some(id)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Wrapper which only changes layout.
+    struct Inset<V> {
+        child: V,
+    }
+
+    impl<V: View> CustomView for Inset<V> {
+        fn child(&self) -> Option<&dyn View> {
+            Some(&self.child)
+        }
+
+        fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+            let sz = self.child.layout(id.child(&0), args);
+            [sz.width + 10.0, sz.height].into()
+        }
+    }
+
+    fn layout(ui: &impl View, cx: &mut Context) -> LocalSize {
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: [100.0, 100.0].into(),
+                cx,
+                text_bounds: &mut |_, _, _| LocalRect::zero(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_custom_leaf() {
+        let mut cx = Context::new();
+        let ui = custom(LeftHalf);
+        assert_eq!(layout(&ui, &mut cx), [40.0, 20.0].into());
+        assert_eq!(cx.layout_rect(cx.root_id).size, [40.0, 20.0].into());
+
+        let root = cx.root_id;
+        assert_eq!(ui.hittest(root, [10.0, 10.0].into(), &mut cx), Some(root));
+        assert_eq!(ui.hittest(root, [30.0, 10.0].into(), &mut cx), None);
+    }
+
+    #[test]
+    fn test_custom_wrapper() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, _| {
+                custom(Inset {
+                    child: rectangle()
+                        .size([20.0, 20.0])
+                        .tap(move |cx| cx[count] += 1)
+                        .role(accesskit::Role::Button),
+                })
+            },
+        );
+        assert_eq!(layout(&ui, &mut cx), [30.0, 20.0].into());
+
+        // Events and accessibility go to the child.
+        let position = [10.0, 10.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        cx.process(&ui, &Event::TouchEnd { id: 0, position });
+        assert_eq!(cx[StateHandle::<i32>::new(cx.root_id)], 1);
+
+        let mut nodes = vec![];
+        assert!(ui.access(cx.root_id, &mut cx, &mut nodes).is_some());
+        assert_eq!(nodes.len(), 1);
+    }
+}
@@ -77,7 +77,9 @@ where
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        self.child.layout(id.child(&0), args)
+        let sz = self.child.layout(id.child(&0), args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
     }
 
     fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
@@ -92,7 +94,12 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
         self.child.gc(id.child(&0), cx, map)
     }
 
@@ -100,8 +107,9 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
+        cx.audit_interactive(id);
         self.child.access(id.child(&0), cx, nodes)
     }
 }
@@ -161,12 +169,14 @@ where
                 if cx.touches[*id] == vid {
                     let delta = *position - cx.previous_position[*id];
                     let button = cx.mouse_button;
-                    actions.push(Box::new((self.func)(
-                        self.binding.get_mut(cx),
-                        delta,
-                        GestureState::Changed,
-                        button,
-                    )));
+                    if let Some(value) = self.binding.try_get_mut(cx) {
+                        actions.push(Box::new((self.func)(
+                            value,
+                            delta,
+                            GestureState::Changed,
+                            button,
+                        )));
+                    }
                     cx.previous_position[*id] = *position;
                 }
             }
@@ -175,12 +185,14 @@ where
                     cx.touches[*id] = ViewId::default();
                     let delta = *position - cx.previous_position[*id];
                     let button = cx.mouse_button;
-                    actions.push(Box::new((self.func)(
-                        self.binding.get_mut(cx),
-                        delta,
-                        GestureState::Ended,
-                        button,
-                    )));
+                    if let Some(value) = self.binding.try_get_mut(cx) {
+                        actions.push(Box::new((self.func)(
+                            value,
+                            delta,
+                            GestureState::Ended,
+                            button,
+                        )));
+                    }
                 }
             }
             _ => self.child.process(event, vid.child(&0), cx, actions),
@@ -192,7 +204,9 @@ where
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        self.child.layout(id.child(&0), args)
+        let sz = self.child.layout(id.child(&0), args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
     }
 
     fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
@@ -207,7 +221,12 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
         self.child.gc(id.child(&0), cx, map)
     }
 
@@ -215,8 +234,9 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
+        cx.audit_interactive(id);
         self.child.access(id.child(&0), cx, nodes)
     }
 }
@@ -43,6 +43,10 @@ where
         (self.func)(cx.init_env(&S::default), cx).commands(id.child(&0), cx, cmds);
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        (self.func)(cx.init_env(&S::default), cx).focus_chain(id.child(&0), cx, chain);
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
         (self.func)(cx.init_env(&S::default), cx).gc(id.child(&0), cx, map);
@@ -52,7 +56,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         (self.func)(cx.init_env(&S::default), cx).access(id.child(&0), cx, nodes)
     }
@@ -101,56 +105,62 @@ where
     ) {
         let old = cx.set_env(&self.env_val);
         self.child.process(event, id.child(&0), cx, actions);
-        old.and_then(|s| cx.set_env(&s));
+        cx.restore_env(old);
     }
 
     fn draw(&self, id: ViewId, args: &mut DrawArgs) {
         let old = args.cx.set_env(&self.env_val);
         self.child.draw(id.child(&0), args);
-        old.and_then(|s| args.cx.set_env(&s));
+        args.cx.restore_env(old);
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
         let old = args.cx.set_env(&self.env_val);
         let sz = self.child.layout(id.child(&0), args);
-        old.and_then(|s| args.cx.set_env(&s));
+        args.cx.restore_env(old);
         sz
     }
 
     fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
         let old = cx.set_env(&self.env_val);
         self.child.dirty(id.child(&0), xform, cx);
-        old.and_then(|s| cx.set_env(&s));
+        cx.restore_env(old);
     }
 
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
         let old = cx.set_env(&self.env_val);
         let r = self.child.hittest(id.child(&0), pt, cx);
-        old.and_then(|s| cx.set_env(&s));
+        cx.restore_env(old);
         r
     }
 
     fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
         let old = cx.set_env(&self.env_val);
         self.child.commands(id.child(&0), cx, cmds);
-        old.and_then(|s| cx.set_env(&s));
+        cx.restore_env(old);
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        let old = cx.set_env(&self.env_val);
+        self.child.focus_chain(id.child(&0), cx, chain);
+        cx.restore_env(old);
     }
 
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         let old = cx.set_env(&self.env_val);
         self.child.gc(id.child(&0), cx, map);
-        old.and_then(|s| cx.set_env(&s));
+        cx.restore_env(old);
     }
 
     fn access(
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         let old = cx.set_env(&self.env_val);
         let r = self.child.access(id.child(&0), cx, nodes);
-        old.and_then(|s| cx.set_env(&s));
+        cx.restore_env(old);
         r
     }
 }
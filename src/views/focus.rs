@@ -18,22 +18,27 @@ where
         cx: &mut Context,
         actions: &mut Vec<Box<dyn Any>>,
     ) {
-        match &event {
-            Event::TouchBegin { id: _, position } => {
-                if self.hittest(vid, *position, cx).is_some() {
-                    cx.focused_id = Some(vid);
-                    cx.set_dirty();
-                }
+        if let Event::TouchBegin { id: _, position } = &event {
+            if self.hittest(vid, *position, cx).is_some() {
+                cx.focused_id = Some(vid);
+                cx.set_dirty();
             }
-            Event::Key(Key::Escape) => {
-                if cx.focused_id == Some(vid) {
-                    cx.focused_id = None;
-                    cx.set_dirty();
-                }
+        }
+
+        cx.process_focusable(vid, |cx| {
+            (self.func)(Some(vid) == cx.focused_id).process(event, vid.child(&0), cx, actions)
+        });
+
+        // Escape releases focus unless the focused view used it.
+        if let Event::KeyDown {
+            key: Key::Escape, ..
+        } = &event
+        {
+            if cx.focused_id == Some(vid) && !cx.key_handled {
+                cx.focused_id = None;
+                cx.set_dirty();
             }
-            _ => (),
         }
-        (self.func)(Some(vid) == cx.focused_id).process(event, vid.child(&0), cx, actions)
     }
 
     fn draw(&self, id: ViewId, args: &mut DrawArgs) {
@@ -56,6 +61,11 @@ where
         (self.func)(Some(id) == cx.focused_id).commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        chain.push(id);
+        (self.func)(Some(id) == cx.focused_id).focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         (self.func)(Some(id) == cx.focused_id).gc(id.child(&0), cx, map)
     }
@@ -64,9 +74,10 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
-        (self.func)(Some(id) == cx.focused_id).access(id.child(&0), cx, nodes)
+        let aid = (self.func)(Some(id) == cx.focused_id).access(id.child(&0), cx, nodes);
+        cx.access_focusable(id, aid, nodes)
     }
 }
 
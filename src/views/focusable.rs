@@ -0,0 +1,286 @@
+use crate::*;
+use std::any::Any;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const FOCUS_RING_WIDTH: f32 = 2.0;
+const FOCUS_RING_RADIUS: f32 = 5.0;
+
+/// Identifies a focusable view so focus can be moved to it
+/// with `Context::focus`. Typically created once and kept in `state`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FocusHandle {
+    id: u64,
+}
+
+impl FocusHandle {
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self {
+            id: NEXT.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for FocusHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct for the `focusable` modifier.
+pub struct Focusable<V> {
+    child: V,
+}
+
+impl<V> Focusable<V>
+where
+    V: View,
+{
+    pub fn new(child: V) -> Self {
+        Self { child }
+    }
+}
+
+impl<V> View for Focusable<V>
+where
+    V: View,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        if let Event::TouchBegin { id: _, position } = &event {
+            if cx.focused_id != Some(id) && self.hittest(id, *position, cx).is_some() {
+                cx.focused_id = Some(id);
+                cx.set_dirty();
+            }
+        }
+
+        cx.process_focusable(id, |cx| {
+            self.child.process(event, id.child(&0), cx, actions)
+        });
+
+        // Escape releases focus unless the focused view used it.
+        if let Event::KeyDown {
+            key: Key::Escape, ..
+        } = &event
+        {
+            if cx.focused_id == Some(id) && !cx.key_handled {
+                cx.focused_id = None;
+                cx.set_dirty();
+            }
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args);
+
+        if args.cx.focused_id == Some(id) {
+            let rect = args.cx.layout[&id]
+                .rect
+                .inflate(FOCUS_RING_WIDTH, FOCUS_RING_WIDTH);
+            let paint = args.vger.color_paint(AZURE_HIGHLIGHT);
+            args.vger.stroke_rect(
+                rect.min(),
+                rect.max(),
+                FOCUS_RING_RADIUS,
+                FOCUS_RING_WIDTH,
+                paint,
+            );
+        }
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let sz = self.child.layout(id.child(&0), args);
+
+        args.cx.layout.insert(
+            id,
+            LayoutBox {
+                rect: LocalRect::new(LocalPoint::zero(), sz),
+                offset: LocalOffset::zero(),
+            },
+        );
+
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        chain.push(id);
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        let aid = self.child.access(id.child(&0), cx, nodes);
+        cx.access_focusable(id, aid, nodes)
+    }
+}
+
+impl<V> private::Sealed for Focusable<V> {}
+
+/// Struct for the `focus_handle` modifier.
+pub struct FocusHandleView<V> {
+    child: V,
+    handle: FocusHandle,
+}
+
+impl<V> FocusHandleView<V>
+where
+    V: View,
+{
+    pub fn new(child: V, handle: FocusHandle) -> Self {
+        Self { child, handle }
+    }
+}
+
+impl<V> View for FocusHandleView<V>
+where
+    V: View,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        // The handle refers to the first focusable view in the subtree.
+        let start = chain.len();
+        self.child.focus_chain(id.child(&0), cx, chain);
+        if let Some(focus_id) = chain.get(start) {
+            cx.focus_handles.insert(self.handle, *focus_id);
+        }
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V> private::Sealed for FocusHandleView<V> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn tab(shift: bool) -> Event {
+        Event::KeyDown {
+            key: Key::Tab,
+            mods: KeyboardModifiers {
+                shift,
+                ..Default::default()
+            },
+            repeat: false,
+        }
+    }
+
+    #[test]
+    fn test_focus_traversal() {
+        let mut cx = Context::new();
+
+        let ui = vstack((
+            rectangle().focusable(),
+            rectangle(),
+            rectangle().focusable(),
+            focus(|_| rectangle()),
+        ));
+
+        let mut chain = vec![];
+        ui.focus_chain(cx.root_id, &mut cx, &mut chain);
+        assert_eq!(chain.len(), 3);
+
+        cx.process(&ui, &tab(false));
+        assert_eq!(cx.focused_id, Some(chain[0]));
+        cx.process(&ui, &tab(false));
+        assert_eq!(cx.focused_id, Some(chain[1]));
+        cx.process(&ui, &tab(false));
+        assert_eq!(cx.focused_id, Some(chain[2]));
+        cx.process(&ui, &tab(false));
+        assert_eq!(cx.focused_id, Some(chain[0]));
+        cx.process(&ui, &tab(true));
+        assert_eq!(cx.focused_id, Some(chain[2]));
+    }
+
+    #[test]
+    fn test_focus_handle() {
+        let mut cx = Context::new();
+        let handle = FocusHandle::new();
+
+        let ui = hstack((
+            rectangle().focusable(),
+            rectangle().focusable().focus_handle(handle),
+        ));
+
+        let mut chain = vec![];
+        ui.focus_chain(cx.root_id, &mut cx, &mut chain);
+
+        // Requests for unknown handles are resolved on the next update.
+        cx.focus_handles.clear();
+        cx.focus(handle);
+        assert_eq!(cx.focused_id, None);
+        cx.update_focus(&ui);
+        assert_eq!(cx.focused_id, Some(chain[1]));
+
+        cx.focused_id = None;
+        cx.focus(handle);
+        assert_eq!(cx.focused_id, Some(chain[1]));
+    }
+}
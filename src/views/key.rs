@@ -1,37 +1,41 @@
 use crate::*;
 use std::any::Any;
 
-/// Struct for the `key` modifier.
-pub struct KeyView<V, F> {
+/// Struct for the `key_down` modifier.
+pub struct KeyDownView<V, F> {
     child: V,
     func: F,
 }
 
-impl<V, F, A> KeyView<V, F>
+impl<V, F, A> KeyDownView<V, F>
 where
     V: View,
-    F: Fn(&mut Context, Key) -> A + 'static,
+    F: Fn(&mut Context, Key, KeyboardModifiers, bool) -> A + 'static,
 {
     pub fn new(v: V, f: F) -> Self {
-        KeyView { child: v, func: f }
+        Self { child: v, func: f }
     }
 }
 
-impl<V, F, A> View for KeyView<V, F>
+impl<V, F, A> View for KeyDownView<V, F>
 where
     V: View,
-    F: Fn(&mut Context, Key) -> A + 'static,
+    F: Fn(&mut Context, Key, KeyboardModifiers, bool) -> A + 'static,
     A: 'static,
 {
     fn process(
         &self,
         event: &Event,
-        _vid: ViewId,
+        vid: ViewId,
         cx: &mut Context,
         actions: &mut Vec<Box<dyn Any>>,
     ) {
-        if let Event::Key(key) = &event {
-            actions.push(Box::new((self.func)(cx, key.clone())));
+        let reached = cx.key_reached;
+        self.child.process(event, vid.child(&0), cx, actions);
+        if let Event::KeyDown { key, mods, repeat } = &event {
+            if cx.is_key_target(reached) {
+                actions.push(Box::new((self.func)(cx, *key, *mods, *repeat)));
+            }
         }
     }
 
@@ -55,6 +59,88 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V, F> private::Sealed for KeyDownView<V, F> {}
+
+/// Struct for the `key_up` modifier.
+pub struct KeyUpView<V, F> {
+    child: V,
+    func: F,
+}
+
+impl<V, F, A> KeyUpView<V, F>
+where
+    V: View,
+    F: Fn(&mut Context, Key, KeyboardModifiers) -> A + 'static,
+{
+    pub fn new(v: V, f: F) -> Self {
+        Self { child: v, func: f }
+    }
+}
+
+impl<V, F, A> View for KeyUpView<V, F>
+where
+    V: View,
+    F: Fn(&mut Context, Key, KeyboardModifiers) -> A + 'static,
+    A: 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        vid: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        let reached = cx.key_reached;
+        self.child.process(event, vid.child(&0), cx, actions);
+        if let Event::KeyUp { key, mods } = &event {
+            if cx.is_key_target(reached) {
+                actions.push(Box::new((self.func)(cx, *key, *mods)));
+            }
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         self.child.gc(id.child(&0), cx, map)
     }
@@ -63,10 +149,114 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         self.child.access(id.child(&0), cx, nodes)
     }
 }
 
-impl<V, F> private::Sealed for KeyView<V, F> {}
+impl<V, F> private::Sealed for KeyUpView<V, F> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_key_up_down() {
+        let mut cx = Context::new();
+
+        let ui = state(
+            || 0,
+            |held, _| {
+                rectangle()
+                    .key_down(move |cx, key, _, repeat| {
+                        if key == Key::Character('c') && !repeat {
+                            cx[held] += 1;
+                        }
+                    })
+                    .key_up(move |cx, key, _| {
+                        if key == Key::Character('c') {
+                            cx[held] -= 1;
+                        }
+                    })
+            },
+        );
+
+        let mods = KeyboardModifiers::default();
+        let key = Key::Character('c');
+        let events = [
+            Event::KeyDown {
+                key,
+                mods,
+                repeat: false,
+            },
+            Event::KeyDown {
+                key,
+                mods,
+                repeat: true,
+            },
+        ];
+
+        let mut actions = vec![];
+        for event in &events {
+            ui.process(event, cx.root_id, &mut cx, &mut actions);
+        }
+
+        let s = StateHandle::<i32>::new(cx.root_id);
+        assert_eq!(cx[s], 1);
+
+        ui.process(
+            &Event::KeyUp { key, mods },
+            cx.root_id,
+            &mut cx,
+            &mut actions,
+        );
+        assert_eq!(cx[s], 0);
+    }
+
+    #[test]
+    fn test_key_routing() {
+        let mut cx = Context::new();
+
+        // Records which handlers saw a key, innermost first.
+        let ui = state(Vec::<&str>::new, |seen, _| {
+            vstack((
+                rectangle()
+                    .key_down(move |cx, _, _, _| cx[seen].push("a"))
+                    .focusable(),
+                rectangle()
+                    .key_down(move |cx, key, _, _| {
+                        cx[seen].push("b");
+                        if key == Key::Enter {
+                            cx.set_handled();
+                        }
+                    })
+                    .focusable()
+                    .key_down(move |cx, _, _, _| cx[seen].push("b parent")),
+            ))
+            .key_down(move |cx, _, _, _| cx[seen].push("root"))
+        });
+
+        let key_down = |key| Event::KeyDown {
+            key,
+            mods: Default::default(),
+            repeat: false,
+        };
+        let s = StateHandle::<Vec<&str>>::new(cx.root_id);
+
+        // Nothing focused, so only handlers outside focusable views.
+        cx.process(&ui, &key_down(Key::Space));
+        assert_eq!(cx[s], vec!["b parent", "root"]);
+
+        cx.process(&ui, &key_down(Key::Tab));
+        cx.process(&ui, &key_down(Key::Tab));
+        cx[s].clear();
+        cx.process(&ui, &key_down(Key::Space));
+        assert_eq!(cx[s], vec!["b", "b parent", "root"]);
+
+        cx[s].clear();
+        cx.process(&ui, &key_down(Key::Enter));
+        assert_eq!(cx[s], vec!["b"]);
+    }
+}
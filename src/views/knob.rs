@@ -9,7 +9,7 @@ fn lerp(x: f32, a: f32, b: f32) -> f32 {
 
 /// Knob for controlling a 0 to 1 floating point parameter.
 pub fn knob(value: impl Binding<f32>) -> impl View {
-    zstack((
+    let knob = zstack((
         circle()
             .color(CLEAR_COLOR)
             .drag_s(value, move |v, delta, _, _| {
@@ -19,12 +19,18 @@ pub fn knob(value: impl Binding<f32>) -> impl View {
             let c = sz.center();
             let r = sz.width().min(sz.height()) / 2.0;
 
-            let paint = vger.color_paint(CONTROL_BACKGROUND);
+            let theme = cx.theme();
+
+            let paint = vger.color_paint(theme.control_background);
 
             vger.stroke_arc(c, r, 2.0, 0.0, std::f32::consts::PI, paint);
 
-            let paint = vger.color_paint(AZURE_HIGHLIGHT);
-            let a0 = lerp(*value.get(cx), THETA_MAX, THETA_MIN);
+            let paint = vger.color_paint(theme.accent_color);
+            let a0 = lerp(
+                value.try_get(cx).copied().unwrap_or_default(),
+                THETA_MAX,
+                THETA_MIN,
+            );
             let a1 = THETA_MAX;
 
             let theta = -(a0 + a1) / 2.0 + std::f32::consts::PI;
@@ -32,7 +38,14 @@ pub fn knob(value: impl Binding<f32>) -> impl View {
 
             vger.stroke_arc(c, r, 2.0, theta, ap, paint);
         }),
-    ))
+    ));
+    access_adjustable(
+        knob,
+        move |cx| value.try_get(cx).copied().unwrap_or_default(),
+        move |cx, v| {
+            value.try_with_mut(cx, |value| *value = v);
+        },
+    )
 }
 
 #[cfg(test)]
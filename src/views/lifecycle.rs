@@ -0,0 +1,145 @@
+use crate::*;
+use std::any::Any;
+use std::rc::Rc;
+
+enum LifecycleKind {
+    Appear,
+    Disappear,
+}
+
+/// Struct for the `on_appear` and `on_disappear` modifiers.
+pub struct LifecycleView<V> {
+    child: V,
+    kind: LifecycleKind,
+    func: LifecycleFn,
+}
+
+impl<V: View> LifecycleView<V> {
+    pub(crate) fn appear(child: V, f: impl Fn(&mut Context) + 'static) -> Self {
+        Self {
+            child,
+            kind: LifecycleKind::Appear,
+            func: Rc::new(f),
+        }
+    }
+
+    pub(crate) fn disappear(child: V, f: impl Fn(&mut Context) + 'static) -> Self {
+        Self {
+            child,
+            kind: LifecycleKind::Disappear,
+            func: Rc::new(f),
+        }
+    }
+}
+
+impl<V: View> View for LifecycleView<V> {
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx)
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        if let Some(lifecycle) = &mut cx.lifecycle {
+            let callbacks = match self.kind {
+                LifecycleKind::Appear => &mut lifecycle.appear,
+                LifecycleKind::Disappear => &mut lifecycle.disappear,
+            };
+            callbacks.push((id, self.func.clone()));
+        }
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V> private::Sealed for LifecycleView<V> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_lifecycle() {
+        let mut cx = Context::new();
+        let log = Rc::new(RefCell::new(vec![]));
+        let events = log.clone();
+        let ui = state(
+            || true,
+            move |show, cx| {
+                let events = events.clone();
+                let on = |name: &'static str| {
+                    let events = events.clone();
+                    move |_: &mut Context| events.borrow_mut().push(name)
+                };
+                let meter = cond(
+                    cx[show],
+                    rectangle()
+                        .on_appear(on("meter appear"))
+                        .on_disappear(on("meter disappear")),
+                    rectangle(),
+                );
+                vstack((
+                    meter,
+                    rectangle()
+                        .on_appear(on("panel appear"))
+                        .on_disappear(on("panel disappear")),
+                ))
+            },
+        );
+        let show = StateHandle::<bool>::new(cx.root_id);
+
+        assert!(cx.gc(&ui));
+        assert_eq!(*log.borrow(), vec!["meter appear", "panel appear"]);
+
+        // Nothing changed, so nothing is called.
+        assert!(!cx.gc(&ui));
+        assert_eq!(log.borrow().len(), 2);
+
+        cx[show] = false;
+        assert!(cx.gc(&ui));
+        assert_eq!(log.borrow()[2..], ["meter disappear"]);
+
+        cx[show] = true;
+        assert!(cx.gc(&ui));
+        assert_eq!(log.borrow()[3..], ["meter appear"]);
+    }
+}
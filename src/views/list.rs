@@ -27,11 +27,13 @@ where
         cx: &mut Context,
         actions: &mut Vec<Box<dyn Any>>,
     ) {
-        for child in &self.ids {
-            let child_id = id.child(child);
-            let offset = cx.layout.entry(child_id).or_default().offset;
-            ((self.func)(child)).process(&event.offset(-offset), child_id, cx, actions);
-        }
+        cx.process_access_node(id, event, |cx| {
+            for child in &self.ids {
+                let child_id = id.child(child);
+                let offset = cx.layout.entry(child_id).or_default().offset;
+                ((self.func)(child)).process(&event.offset(-offset), child_id, cx, actions);
+            }
+        })
     }
 
     fn draw(&self, id: ViewId, args: &mut DrawArgs) {
@@ -50,7 +52,7 @@ where
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        match self.orientation {
+        let sz = match self.orientation {
             ListOrientation::Horizontal => {
                 let n = self.ids.len() as f32;
                 let proposed_child_size = LocalSize::new(args.sz.width / n, args.sz.height);
@@ -141,10 +143,13 @@ where
                 }
                 args.sz
             }
-        }
+        };
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
     }
 
     fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        cx.set_access_bounds(id, xform);
         for child in &self.ids {
             let child_id = id.child(child);
             let offset = cx.layout.entry(child_id).or_default().offset;
@@ -173,6 +178,13 @@ where
         }
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        for child in &self.ids {
+            let child_id = id.child(child);
+            ((self.func)(child)).focus_chain(child_id, cx, chain)
+        }
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
         for child in &self.ids {
@@ -186,18 +198,22 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
-        let mut builder = accesskit::NodeBuilder::new(accesskit::Role::List);
+        let mut builder = cx.access_node(id, accesskit::Role::List);
 
-        let children: Vec<accesskit::NodeId> = self
-            .ids
-            .iter()
-            .filter_map(|child| ((self.func)(child)).access(id.child(child), cx, nodes))
-            .collect();
+        let (children, actions) = cx.access_children(id, |cx| {
+            self.ids
+                .iter()
+                .filter_map(|child| ((self.func)(child)).access(id.child(child), cx, nodes))
+                .collect::<Vec<_>>()
+        });
 
+        for action in actions {
+            builder.add_action(action);
+        }
         builder.set_children(children);
-        nodes.push((id.access_id(), builder.build(&mut cx.access_node_classes)));
+        nodes.push((id.access_id(), builder));
         Some(id.access_id())
     }
 }
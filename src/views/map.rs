@@ -57,6 +57,11 @@ where
         (self.func)(StateHandle::new(id), cx).commands(id.child(&0), cx, cmds);
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        cx.set_state(id, self.value.clone());
+        (self.func)(StateHandle::new(id), cx).focus_chain(id.child(&0), cx, chain);
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         cx.set_state(id, self.value.clone());
         map.push(id);
@@ -67,7 +72,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         cx.set_state(id, self.value.clone());
         (self.func)(StateHandle::new(id), cx).access(id.child(&0), cx, nodes)
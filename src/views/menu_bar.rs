@@ -0,0 +1,799 @@
+use crate::*;
+use std::any::Any;
+
+pub(crate) const MENU_FONT_SIZE: u32 = 14;
+const MENU_BAR_HEIGHT: f32 = 24.0;
+pub(crate) const MENU_ROW_HEIGHT: f32 = 22.0;
+const MENU_PADDING: f32 = 10.0;
+const MENU_KEY_GAP: f32 = 30.0;
+
+/// A node in the menu hierarchy.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MenuItem {
+    title: String,
+    /// Command path, for items which run a command.
+    path: Option<String>,
+    key: Option<KeyChord>,
+    enabled: bool,
+    checked: bool,
+    children: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// An item which runs a command.
+    pub(crate) fn new(cmd: &CommandInfo) -> Self {
+        Self {
+            title: cmd.title.clone(),
+            path: Some(cmd.path.clone()),
+            key: cmd.key,
+            enabled: cmd.enabled,
+            checked: cmd.checked,
+            children: vec![],
+        }
+    }
+}
+
+/// Builds the menu hierarchy by splitting command paths on `:`.
+fn menu_items(cmds: &[CommandInfo]) -> Vec<MenuItem> {
+    let mut items: Vec<MenuItem> = vec![];
+    for cmd in cmds {
+        let parts: Vec<&str> = cmd.path.split(':').collect();
+        let mut level = &mut items;
+        for (i, part) in parts.iter().enumerate() {
+            let pos = match level.iter().position(|item| item.title == *part) {
+                Some(pos) => pos,
+                None => {
+                    level.push(MenuItem {
+                        title: part.to_string(),
+                        path: None,
+                        key: None,
+                        enabled: true,
+                        checked: false,
+                        children: vec![],
+                    });
+                    level.len() - 1
+                }
+            };
+            if i == parts.len() - 1 {
+                level[pos].path = Some(cmd.path.clone());
+                level[pos].title = cmd.title.clone();
+                level[pos].key = cmd.key;
+                level[pos].enabled = cmd.enabled;
+                level[pos].checked = cmd.checked;
+            }
+            level = &mut level[pos].children;
+        }
+    }
+    items
+}
+
+/// Gets the item at a path of indices.
+fn item_at<'a>(items: &'a [MenuItem], path: &[usize]) -> Option<&'a MenuItem> {
+    let mut level = items;
+    let mut item = None;
+    for i in path {
+        let it = level.get(*i)?;
+        level = &it.children;
+        item = Some(it);
+    }
+    item
+}
+
+/// Gets the rows of the dropdown for the item at `path`, if it has any.
+fn dropdown_items<'a>(items: &'a [MenuItem], path: &[usize]) -> Option<&'a [MenuItem]> {
+    item_at(items, path)
+        .map(|item| item.children.as_slice())
+        .filter(|rows| !rows.is_empty())
+}
+
+/// Opens a top level menu, highlighting its first row.
+fn open_menu(items: &[MenuItem], i: usize) -> Vec<usize> {
+    if items[i].children.is_empty() {
+        vec![i]
+    } else {
+        vec![i, 0]
+    }
+}
+
+/// The area of a row in a dropdown, counting from the top.
+pub(crate) fn menu_row_rect(dropdown: &LocalRect, row: usize) -> LocalRect {
+    LocalRect::new(
+        [
+            dropdown.min_x(),
+            dropdown.max_y() - (row + 1) as f32 * MENU_ROW_HEIGHT,
+        ]
+        .into(),
+        [dropdown.width(), MENU_ROW_HEIGHT].into(),
+    )
+}
+
+#[derive(Clone, Default)]
+struct MenuBarState {
+    /// The open menu, followed by the highlighted row in each
+    /// open dropdown. Empty when the menus are closed.
+    open: Vec<usize>,
+
+    /// Area of the bar.
+    bar: LocalRect,
+
+    /// Menu titles in the bar.
+    titles: Vec<LocalRect>,
+
+    /// Open dropdowns, from the top level down, in the space of
+    /// the overlay showing them.
+    dropdowns: Vec<LocalRect>,
+
+    /// Transform from the last draw, for placing the dropdowns.
+    xform: LocalToWorld,
+
+    /// Theme from the last draw, for drawing the dropdowns.
+    theme: Theme,
+}
+
+fn menu_state(cx: &mut Context, id: ViewId) -> &mut MenuBarState {
+    cx.init_state(id, &MenuBarState::default);
+    cx.state_map
+        .get_mut(&id)
+        .unwrap()
+        .state
+        .downcast_mut()
+        .unwrap()
+}
+
+/// Opens menus along a path, showing their dropdowns below the open
+/// title, or closes them all if `open` is empty.
+fn set_open(id: ViewId, cx: &mut Context, open: Vec<usize>) {
+    let state = menu_state(cx, id);
+    if state.open == open {
+        return;
+    }
+    let title = open
+        .first()
+        .map(|i| state.titles.get(*i).copied().unwrap_or_default());
+    let anchor = title.map(|title| state.xform.outer_transformed_rect(&title));
+    state.open = open;
+
+    // Mark the state dirty so enclosing layout is recomputed.
+    cx.state_map.get_mut(&id).unwrap().dirty = true;
+    cx.set_dirty();
+
+    match anchor {
+        Some(anchor) => cx.present_overlay(id, anchor, MenuDropdowns { owner: id }),
+        None => cx.dismiss_overlay(id),
+    }
+}
+
+/// Closes the menus if their overlay was dismissed, or the commands
+/// changed and the open item is gone.
+fn close_stale(id: ViewId, cx: &mut Context, items: &[MenuItem]) {
+    let presented = cx.is_overlay_presented(id);
+    let open = &menu_state(cx, id).open;
+    if !open.is_empty() && (!presented || item_at(items, open).is_none()) {
+        set_open(id, cx, vec![]);
+    }
+}
+
+/// Runs the command for an item and closes the menus, or opens its submenu.
+fn pick(id: ViewId, cx: &mut Context, items: &[MenuItem], path: Vec<usize>) {
+    if let Some(item) = item_at(items, &path) {
+        if !item.children.is_empty() {
+            let mut open = path;
+            open.push(0);
+            set_open(id, cx, open);
+        } else if item.enabled {
+            if let Some(cmd) = &item.path {
+                cx.run_command(cmd);
+            }
+            set_open(id, cx, vec![]);
+        }
+    }
+}
+
+fn menu_key(id: ViewId, cx: &mut Context, items: &[MenuItem], key: Key) {
+    let mut open = menu_state(cx, id).open.clone();
+    let top = items.len();
+    match key {
+        Key::Escape => {
+            if open.len() > 2 {
+                open.pop();
+            } else {
+                open.clear();
+            }
+        }
+        Key::ArrowDown | Key::ArrowUp => {
+            let parent = &open[..open.len().max(2) - 1];
+            if let Some(rows) = dropdown_items(items, parent) {
+                let n = rows.len();
+                let down = key == Key::ArrowDown;
+                if open.len() < 2 {
+                    open.push(if down { 0 } else { n - 1 });
+                } else if let Some(row) = open.last_mut() {
+                    *row = if down {
+                        (*row + 1) % n
+                    } else {
+                        (*row + n - 1) % n
+                    };
+                }
+            }
+        }
+        Key::ArrowRight => match item_at(items, &open) {
+            Some(item) if open.len() > 1 && !item.children.is_empty() => open.push(0),
+            _ => open = open_menu(items, (open[0] + 1) % top),
+        },
+        Key::ArrowLeft => {
+            if open.len() > 2 {
+                open.pop();
+            } else {
+                open = open_menu(items, (open[0] + top - 1) % top);
+            }
+        }
+        Key::Enter | Key::Space => return pick(id, cx, items, open),
+        _ => return,
+    }
+    set_open(id, cx, open);
+}
+
+enum MenuHit {
+    Title(usize),
+    Bar,
+}
+
+/// Struct for `menu_bar`.
+pub struct MenuBar<V> {
+    child: V,
+}
+
+impl<V> MenuBar<V>
+where
+    V: View,
+{
+    fn hit(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<MenuHit> {
+        let state = menu_state(cx, id);
+        if let Some(i) = state.titles.iter().position(|r| r.contains(pt)) {
+            return Some(MenuHit::Title(i));
+        }
+        if state.bar.contains(pt) {
+            return Some(MenuHit::Bar);
+        }
+        None
+    }
+}
+
+impl<V> View for MenuBar<V>
+where
+    V: View,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        let items = menu_items(&cx.commands);
+        close_stale(id, cx, &items);
+        let is_open = !menu_state(cx, id).open.is_empty();
+
+        // The dropdowns are an overlay, so they get events first. While
+        // they're open, moving over the titles switches menus.
+        match event {
+            Event::TouchBegin { position, .. } => match self.hit(id, *position, cx) {
+                Some(MenuHit::Title(i)) => {
+                    match items.get(i) {
+                        Some(item) if item.children.is_empty() => pick(id, cx, &items, vec![i]),
+                        Some(_) => set_open(id, cx, vec![i]),
+                        None => (),
+                    }
+                    return;
+                }
+                Some(MenuHit::Bar) => return,
+                None => (),
+            },
+            Event::TouchMove { position, .. } if is_open => {
+                if let Some(MenuHit::Title(i)) = self.hit(id, *position, cx) {
+                    if items.get(i).is_some_and(|item| !item.children.is_empty())
+                        && menu_state(cx, id).open[0] != i
+                    {
+                        set_open(id, cx, vec![i]);
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        // Keys go to the focused view first, so the menus only get what
+        // it leaves.
+        let reached = cx.key_reached;
+        let offset = cx.layout.get(&id).map(|b| b.offset).unwrap_or_default();
+        self.child
+            .process(&event.offset(-offset), id.child(&0), cx, actions);
+
+        if let Event::KeyDown { key: Key::F10, .. } = event {
+            if cx.is_key_target(reached) && !items.is_empty() {
+                set_open(id, cx, open_menu(&items, 0));
+                cx.set_handled();
+            }
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        let offset = args.cx.layout[&id].offset;
+        args.vger.save();
+        args.vger.translate(offset);
+        self.child.draw(id.child(&0), args);
+        args.vger.restore();
+
+        let items = menu_items(&args.cx.commands);
+        let theme = args.cx.theme();
+        let state = menu_state(args.cx, id);
+        state.xform = args.vger.current_transform();
+        state.theme = theme;
+        let state = state.clone();
+        let vger = &mut args.vger;
+
+        let paint = vger.color_paint(theme.control_background);
+        vger.fill_rect(state.bar, 0.0, paint);
+
+        for (i, (item, rect)) in items.iter().zip(&state.titles).enumerate() {
+            if state.open.first() == Some(&i) {
+                let paint = vger.color_paint(theme.button_hover_color);
+                vger.fill_rect(*rect, 0.0, paint);
+            }
+            draw_label(
+                vger,
+                &item.title,
+                rect.min_x() + MENU_PADDING,
+                rect,
+                theme.text_color,
+            );
+        }
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let sz = args.sz;
+        let child_avail = LocalSize::new(sz.width, (sz.height - MENU_BAR_HEIGHT).max(0.0));
+        let child_sz = self.child.layout(id.child(&0), &mut args.size(child_avail));
+
+        // Center the content below the bar.
+        let offset = ((child_avail - child_sz) / 2.0).into();
+        args.cx.layout.insert(
+            id,
+            LayoutBox {
+                rect: LocalRect::new(LocalPoint::zero(), sz),
+                offset,
+            },
+        );
+
+        let items = menu_items(&args.cx.commands);
+        let text_width =
+            |args: &mut LayoutArgs, s: &str| (args.text_bounds)(s, MENU_FONT_SIZE, None).width();
+
+        let bar_y = sz.height - MENU_BAR_HEIGHT;
+        let mut titles = vec![];
+        let mut x = 0.0;
+        for item in &items {
+            let w = text_width(args, &item.title) + 2.0 * MENU_PADDING;
+            titles.push(LocalRect::new(
+                [x, bar_y].into(),
+                [w, MENU_BAR_HEIGHT].into(),
+            ));
+            x += w;
+        }
+
+        let state = menu_state(args.cx, id);
+        state.bar = LocalRect::new([0.0, bar_y].into(), [sz.width, MENU_BAR_HEIGHT].into());
+        state.titles = titles;
+
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        let offset = cx.layout[&id].offset;
+        self.child
+            .dirty(id.child(&0), xform.pre_translate(offset), cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        if self.hit(id, pt, cx).is_some() {
+            return Some(id);
+        }
+        let offset = cx.layout.get(&id).map(|b| b.offset).unwrap_or_default();
+        self.child.hittest(id.child(&0), pt - offset, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V> private::Sealed for MenuBar<V> {}
+
+/// The open dropdowns, presented as an overlay by `MenuBar`.
+struct MenuDropdowns {
+    /// The `MenuBar` which opened the menus, and holds their state.
+    owner: ViewId,
+}
+
+impl MenuDropdowns {
+    /// The dropdown level and row under a point.
+    fn row_at(&self, cx: &mut Context, pt: LocalPoint) -> Option<(usize, usize)> {
+        let state = menu_state(cx, self.owner);
+        let (level, dropdown) = state
+            .dropdowns
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, dropdown)| dropdown.contains(pt))?;
+        let row = ((dropdown.max_y() - pt.y) / MENU_ROW_HEIGHT) as usize;
+        Some((level, row))
+    }
+}
+
+impl View for MenuDropdowns {
+    fn process(
+        &self,
+        event: &Event,
+        _id: ViewId,
+        cx: &mut Context,
+        _actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        let items = menu_items(&cx.commands);
+        close_stale(self.owner, cx, &items);
+        if menu_state(cx, self.owner).open.is_empty() {
+            if event.is_keyboard() {
+                cx.set_handled();
+            }
+            return;
+        }
+
+        match event {
+            Event::TouchBegin { position, .. } => match self.row_at(cx, *position) {
+                Some((level, row)) => {
+                    if let Some(open) = menu_state(cx, self.owner).open.get(..=level) {
+                        let mut path = open.to_vec();
+                        path.push(row);
+                        pick(self.owner, cx, &items, path);
+                    }
+                }
+                None => set_open(self.owner, cx, vec![]),
+            },
+            Event::TouchMove { position, .. } => {
+                if let Some((level, row)) = self.row_at(cx, *position) {
+                    let mut open = menu_state(cx, self.owner).open.clone();
+                    open.truncate(level + 1);
+                    open.push(row);
+                    set_open(self.owner, cx, open);
+                }
+            }
+            Event::KeyDown { key, .. } => {
+                menu_key(self.owner, cx, &items, *key);
+                cx.set_handled();
+            }
+            _ if event.is_keyboard() => cx.set_handled(),
+            _ => (),
+        }
+    }
+
+    fn draw(&self, _id: ViewId, args: &mut DrawArgs) {
+        let items = menu_items(&args.cx.commands);
+        let state = menu_state(args.cx, self.owner).clone();
+        let vger = &mut args.vger;
+
+        for (level, dropdown) in state.dropdowns.iter().enumerate() {
+            let rows = match state
+                .open
+                .get(..=level)
+                .and_then(|path| dropdown_items(&items, path))
+            {
+                Some(rows) => rows,
+                None => break,
+            };
+            let paint = vger.color_paint(state.theme.button_color);
+            vger.fill_rect(*dropdown, 0.0, paint);
+
+            for (j, row) in rows.iter().enumerate() {
+                let rect = menu_row_rect(dropdown, j);
+                if state.open.get(level + 1) == Some(&j) {
+                    let paint = vger.color_paint(state.theme.accent_background);
+                    vger.fill_rect(rect, 0.0, paint);
+                }
+                draw_menu_row(vger, &rect, row, &state.theme);
+            }
+        }
+    }
+
+    fn layout(&self, _id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let items = menu_items(&args.cx.commands);
+        let open = menu_state(args.cx, self.owner).open.clone();
+
+        // Lay out down from the top of the first dropdown, then move
+        // everything up into the overlay.
+        let mut dropdowns: Vec<LocalRect> = vec![];
+        for level in 0..open.len() {
+            let rows = match dropdown_items(&items, &open[..=level]) {
+                Some(rows) => rows,
+                None => break,
+            };
+
+            let width = rows
+                .iter()
+                .map(|row| menu_row_width(args, row))
+                .fold(0.0, f32::max);
+
+            // Submenus line up with the row which opened them.
+            let (x, top) = match dropdowns.last() {
+                Some(parent) => (parent.max_x(), menu_row_rect(parent, open[level]).max_y()),
+                None => (0.0, 0.0),
+            };
+            let height = rows.len() as f32 * MENU_ROW_HEIGHT;
+            dropdowns.push(LocalRect::new(
+                [x, top - height].into(),
+                [width, height].into(),
+            ));
+        }
+
+        let bottom = dropdowns.iter().map(|r| r.min_y()).fold(0.0, f32::min);
+        let width = dropdowns.iter().map(|r| r.max_x()).fold(0.0, f32::max);
+        for dropdown in &mut dropdowns {
+            dropdown.origin.y -= bottom;
+        }
+        menu_state(args.cx, self.owner).dropdowns = dropdowns;
+
+        [width, -bottom].into()
+    }
+}
+
+impl private::Sealed for MenuDropdowns {}
+
+/// How wide a dropdown must be to fit a row.
+pub(crate) fn menu_row_width(args: &mut LayoutArgs, row: &MenuItem) -> f32 {
+    let mut text_width = |s: &str| (args.text_bounds)(s, MENU_FONT_SIZE, None).width();
+    let mut w = text_width(&row.title) + 2.0 * MENU_PADDING;
+    if let Some(key) = row.key {
+        w += MENU_KEY_GAP + text_width(&key.to_string());
+    }
+    if !row.children.is_empty() {
+        w += MENU_KEY_GAP;
+    }
+    w
+}
+
+/// Draws a dropdown row: a check mark, the title, and the hotkey or
+/// an arrow for submenus.
+pub(crate) fn draw_menu_row(vger: &mut Vger, rect: &LocalRect, row: &MenuItem, theme: &Theme) {
+    if row.checked {
+        let paint = vger.color_paint(theme.text_color);
+        let center = [rect.min_x() + MENU_PADDING / 2.0, rect.center().y];
+        vger.fill_circle(center, 3.0, paint);
+    }
+    let color = if row.enabled {
+        theme.text_color
+    } else {
+        theme.disabled_text_color
+    };
+    draw_label(vger, &row.title, rect.min_x() + MENU_PADDING, rect, color);
+
+    let right = if !row.children.is_empty() {
+        Some(">".to_string())
+    } else {
+        row.key.map(|key| key.to_string())
+    };
+    if let Some(right) = right {
+        let w = vger.text_bounds(&right, MENU_FONT_SIZE, None).width();
+        let x = rect.max_x() - MENU_PADDING - w;
+        draw_label(vger, &right, x, rect, theme.disabled_text_color);
+    }
+}
+
+/// Draws a line of menu text starting at `x`, centered vertically in `rect`.
+pub(crate) fn draw_label(vger: &mut Vger, text: &str, x: f32, rect: &LocalRect, color: Color) {
+    let bounds = vger.text_bounds(text, MENU_FONT_SIZE, None);
+    vger.save();
+    vger.translate([
+        x - bounds.origin.x,
+        rect.min_y() + (rect.height() - bounds.height()) / 2.0 - bounds.origin.y,
+    ]);
+    vger.text(text, MENU_FONT_SIZE, color, None);
+    vger.restore();
+}
+
+/// Shows the app's commands in a menu bar above `view`.
+///
+/// Commands are grouped into menus by splitting their paths on `:`, so
+/// "File:New" is the New item of the File menu. Press F10 to use the
+/// menus from the keyboard.
+pub fn menu_bar(view: impl View) -> impl View {
+    MenuBar { child: view }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_menu_items() {
+        let cmd = |path: &str| CommandInfo::new(path, None);
+        let items = menu_items(&[
+            cmd("File:New"),
+            cmd("Edit:Copy"),
+            cmd("File:Recent:One"),
+            cmd("Quit"),
+        ]);
+
+        let titles: Vec<&str> = items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, ["File", "Edit", "Quit"]);
+        assert_eq!(items[0].children.len(), 2);
+        assert_eq!(
+            item_at(&items, &[0, 1, 0]).unwrap().path.as_deref(),
+            Some("File:Recent:One")
+        );
+        assert!(dropdown_items(&items, &[2]).is_none());
+    }
+
+    fn layout(ui: &impl View, cx: &mut Context) {
+        let sz = [200.0, 200.0].into();
+        let mut text_bounds = |_: &str, _, _| LocalRect::zero();
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz,
+                cx,
+                text_bounds: &mut text_bounds,
+            },
+        );
+        cx.layout_overlays(sz, &mut text_bounds);
+    }
+
+    fn touch(cx: &mut Context, ui: &impl View, position: LocalPoint) {
+        cx.process(ui, &Event::TouchBegin { id: 0, position });
+        cx.process(ui, &Event::TouchEnd { id: 0, position });
+    }
+
+    #[test]
+    fn test_menu_bar() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, _| {
+                menu_bar(
+                    rectangle()
+                        .command("File:New", None, move |cx| cx[count] += 1)
+                        .command("File:Open", None, move |cx| cx[count] += 10),
+                )
+            },
+        );
+        cx.update_commands(&ui);
+        layout(&ui, &mut cx);
+        let s = StateHandle::<i32>::new(cx.root_id);
+
+        // Titles are 20 wide with empty text, in a bar at the top.
+        touch(&mut cx, &ui, [10.0, 190.0].into());
+        layout(&ui, &mut cx);
+
+        // The dropdown hangs below the title, above the rest of the UI.
+        assert_eq!(
+            cx.overlays[0].rect,
+            WorldRect::new([0.0, 132.0].into(), [20.0, 44.0].into())
+        );
+
+        // Pick the first row of the dropdown.
+        touch(&mut cx, &ui, [10.0, 170.0].into());
+        assert_eq!(cx[s], 1);
+
+        // Keyboard: open, move down and pick.
+        let key_down = |key| Event::KeyDown {
+            key,
+            mods: Default::default(),
+            repeat: false,
+        };
+        cx.process(&ui, &key_down(Key::F10));
+        cx.process(&ui, &key_down(Key::ArrowDown));
+        cx.process(&ui, &key_down(Key::Enter));
+        assert_eq!(cx[s], 11);
+
+        // Closed menus pass events through.
+        cx.process(&ui, &key_down(Key::Enter));
+        assert_eq!(cx[s], 11);
+    }
+
+    #[test]
+    fn test_menu_bar_key_routing() {
+        let mut cx = Context::new();
+        let ui = state(
+            || 0,
+            |count, _| {
+                menu_bar(
+                    rectangle()
+                        .key_down(move |cx, key, _, _| {
+                            if key == Key::F10 {
+                                cx[count] += 1;
+                                cx.set_handled();
+                            }
+                        })
+                        .focusable()
+                        .command("File:New", None, |_| ()),
+                )
+            },
+        );
+        cx.update_commands(&ui);
+        layout(&ui, &mut cx);
+        let s = StateHandle::<i32>::new(cx.root_id);
+        let bar = cx.root_id.child(&0);
+
+        let key_down = |key| Event::KeyDown {
+            key,
+            mods: Default::default(),
+            repeat: false,
+        };
+
+        // The focused view gets F10 first.
+        cx.process(&ui, &key_down(Key::Tab));
+        cx.process(&ui, &key_down(Key::F10));
+        assert_eq!(cx[s], 1);
+        assert!(menu_state(&mut cx, bar).open.is_empty());
+
+        // Without focus, the menus get it.
+        cx.focused_id = None;
+        cx.process(&ui, &key_down(Key::F10));
+        assert_eq!(cx[s], 1);
+        assert_eq!(menu_state(&mut cx, bar).open, vec![0, 0]);
+    }
+
+    #[test]
+    fn test_menu_bar_commands_change() {
+        let mut cx = Context::new();
+        let ui = state(
+            || true,
+            |more, cx| {
+                let content = rectangle().command("File:New", None, |_| ());
+                menu_bar(if cx[more] {
+                    any_view(content.command("Edit:Copy", None, |_| ()))
+                } else {
+                    any_view(content)
+                })
+            },
+        );
+        cx.update_commands(&ui);
+        layout(&ui, &mut cx);
+        let more = StateHandle::<bool>::new(cx.root_id);
+        let bar = cx.root_id.child(&0);
+
+        // Open Edit, then remove it.
+        touch(&mut cx, &ui, [30.0, 190.0].into());
+        layout(&ui, &mut cx);
+        assert_eq!(menu_state(&mut cx, bar).open, vec![1]);
+        cx[more] = false;
+        cx.update_commands(&ui);
+
+        // The menu closes instead of using the stale index.
+        cx.process(
+            &ui,
+            &Event::KeyDown {
+                key: Key::ArrowRight,
+                mods: Default::default(),
+                repeat: false,
+            },
+        );
+        assert!(menu_state(&mut cx, bar).open.is_empty());
+        layout(&ui, &mut cx);
+    }
+}
@@ -1,3 +1,7 @@
+mod access_action;
+pub use access_action::*;
+mod accessibility;
+pub use accessibility::*;
 mod anim;
 pub use anim::*;
 mod anyview;
@@ -8,12 +12,22 @@ mod button;
 pub use button::*;
 mod canvas;
 pub use canvas::*;
+mod change;
+pub use change::*;
 mod clip;
 pub use clip::*;
 mod command;
 pub use command::*;
+mod command_palette;
+pub use command_palette::*;
+mod context_menu;
+pub use context_menu::*;
+mod computed;
+pub use computed::*;
 mod cond;
 pub use cond::*;
+mod custom;
+pub use custom::*;
 mod drag;
 pub use drag::*;
 mod emptyview;
@@ -24,6 +38,8 @@ mod flex;
 pub use flex::*;
 mod focus;
 pub use focus::*;
+mod focusable;
+pub use focusable::*;
 mod geom;
 pub use geom::*;
 mod handle;
@@ -34,10 +50,14 @@ mod key;
 pub use key::*;
 mod knob;
 pub use knob::*;
+mod lifecycle;
+pub use lifecycle::*;
 mod list;
 pub use list::*;
 mod map;
 pub use map::*;
+mod menu_bar;
+pub use menu_bar::*;
 mod modview;
 pub use modview::*;
 mod offset;
@@ -50,6 +70,8 @@ mod role;
 pub use role::*;
 mod shapes;
 pub use shapes::*;
+mod sheet;
+pub use sheet::*;
 mod size;
 pub use size::*;
 mod slider;
@@ -65,9 +87,15 @@ mod tap;
 pub use tap::*;
 mod text_editor;
 pub use text_editor::*;
+mod text_field;
+pub use text_field::*;
+mod text_input;
+pub use text_input::*;
 mod text;
 pub use text::*;
 mod toggle;
 pub use toggle::*;
+mod tooltip;
+pub use tooltip::*;
 mod window;
 pub use window::*;
@@ -42,6 +42,10 @@ where
         (self.func)(self.value.clone(), cx).commands(id.child(&0), cx, cmds);
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        (self.func)(self.value.clone(), cx).focus_chain(id.child(&0), cx, chain);
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
         (self.func)(self.value.clone(), cx).gc(id.child(&0), cx, map);
@@ -51,7 +55,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         (self.func)(self.value.clone(), cx).access(id.child(&0), cx, nodes)
     }
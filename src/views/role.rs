@@ -28,7 +28,9 @@ where
         cx: &mut Context,
         actions: &mut Vec<Box<dyn Any>>,
     ) {
-        self.child.process(event, id.child(&0), cx, actions);
+        cx.process_access_node(id, event, |cx| {
+            self.child.process(event, id.child(&0), cx, actions)
+        });
     }
 
     fn draw(&self, id: ViewId, args: &mut DrawArgs) {
@@ -36,10 +38,13 @@ where
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        self.child.layout(id.child(&0), args)
+        let sz = self.child.layout(id.child(&0), args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
     }
 
     fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        cx.set_access_bounds(id, xform);
         self.child.dirty(id.child(&0), xform, cx);
     }
 
@@ -51,7 +56,12 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
         self.child.gc(id.child(&0), cx, map)
     }
 
@@ -59,16 +69,20 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
-        let child_aid = self.child.access(id.child(&0), cx, nodes);
+        let (child_aid, actions) =
+            cx.access_children(id, |cx| self.child.access(id.child(&0), cx, nodes));
         let aid = id.access_id();
-        let mut builder = accesskit::NodeBuilder::new(self.role);
+        let mut builder = cx.access_node(id, self.role);
+        for action in actions {
+            builder.add_action(action);
+        }
         builder.set_children(match child_aid {
             Some(cid) => vec![cid],
             None => vec![],
         });
-        nodes.push((aid, builder.build(&mut cx.access_node_classes)));
+        nodes.push((aid, builder));
         Some(aid)
     }
 }
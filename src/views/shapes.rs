@@ -53,6 +53,18 @@ impl View for Circle {
     fn gc(&self, id: ViewId, _cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
     }
+
+    fn access(
+        &self,
+        _id: ViewId,
+        cx: &mut Context,
+        _nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        if let Paint::Color(color) = self.paint {
+            cx.audit_fill(color);
+        }
+        None
+    }
 }
 
 impl private::Sealed for Circle {}
@@ -126,6 +138,18 @@ impl View for Rectangle {
     fn gc(&self, id: ViewId, _cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
     }
+
+    fn access(
+        &self,
+        _id: ViewId,
+        cx: &mut Context,
+        _nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        if let Paint::Color(color) = self.paint {
+            cx.audit_fill(color);
+        }
+        None
+    }
 }
 
 impl private::Sealed for Rectangle {}
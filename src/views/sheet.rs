@@ -0,0 +1,442 @@
+use crate::*;
+use accesskit::Role;
+use std::any::Any;
+use std::rc::Rc;
+
+/// Covers the content underneath a modal view.
+const DIM_COLOR: Color = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.5,
+};
+
+type SheetAction = Rc<dyn Fn(&mut Context)>;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum AlertButtonKind {
+    Normal,
+    Default,
+    Cancel,
+}
+
+/// A button in an `alert`. Tapping it runs its action and dismisses the alert.
+pub struct AlertButton {
+    title: String,
+    action: SheetAction,
+    kind: AlertButtonKind,
+}
+
+impl AlertButton {
+    pub fn new(title: &str, action: impl Fn(&mut Context) + 'static) -> Self {
+        Self {
+            title: title.into(),
+            action: Rc::new(action),
+            kind: AlertButtonKind::Normal,
+        }
+    }
+
+    /// A button which is also triggered by Enter.
+    pub fn default_action(title: &str, action: impl Fn(&mut Context) + 'static) -> Self {
+        Self {
+            kind: AlertButtonKind::Default,
+            ..Self::new(title, action)
+        }
+    }
+
+    /// A button which is also triggered by Escape.
+    pub fn cancel(title: &str, action: impl Fn(&mut Context) + 'static) -> Self {
+        Self {
+            kind: AlertButtonKind::Cancel,
+            ..Self::new(title, action)
+        }
+    }
+}
+
+/// Struct for the `sheet` and `alert` modifiers.
+///
+/// The dim and the dialog are drawn within the view's own rect, centered,
+/// so a sheet is expected to be attached to the root view to cover the
+/// whole window.
+pub struct Sheet<V, B, S> {
+    child: V,
+    is_presented: B,
+    sheet: S,
+    role: Role,
+    title: Option<String>,
+    cancel: Option<SheetAction>,
+    default: Option<SheetAction>,
+}
+
+impl<V, B, S> Sheet<V, B, S>
+where
+    V: View,
+    B: Binding<bool>,
+    S: View,
+{
+    pub fn new(child: V, is_presented: B, sheet: S) -> Self {
+        Self {
+            child,
+            is_presented,
+            sheet,
+            role: Role::Dialog,
+            title: None,
+            cancel: None,
+            default: None,
+        }
+    }
+
+    fn dismiss(&self, cx: &mut Context, action: &Option<SheetAction>) {
+        if let Some(action) = action {
+            action(cx);
+        }
+        *self.is_presented.get_mut(cx) = false;
+    }
+}
+
+impl<V, B> Sheet<V, B, AnyView>
+where
+    V: View,
+    B: Binding<bool>,
+{
+    pub fn alert(
+        child: V,
+        is_presented: B,
+        title: &str,
+        message: &str,
+        buttons: Vec<AlertButton>,
+    ) -> Self {
+        let find = |kind| {
+            buttons
+                .iter()
+                .find(|b| b.kind == kind)
+                .map(|b| b.action.clone())
+        };
+        let cancel = find(AlertButtonKind::Cancel);
+        let default = find(AlertButtonKind::Default);
+
+        let buttons = Rc::new(buttons);
+        let title_text = title.to_string();
+        let message = message.to_string();
+        let alert = state(
+            || (),
+            move |_, cx| {
+                let theme = cx.theme();
+                let buttons = buttons.clone();
+                let row = hlist((0..buttons.len()).collect(), move |i| {
+                    let action = buttons[*i].action.clone();
+                    button(text(&buttons[*i].title), move |cx| {
+                        action(cx);
+                        *is_presented.get_mut(cx) = false;
+                    })
+                    .padding(theme.spacing)
+                });
+
+                vstack((
+                    text(&title_text).padding(theme.spacing),
+                    text(&message).padding(theme.spacing),
+                    row,
+                ))
+                .padding(Auto)
+                .background(
+                    rectangle()
+                        .corner_radius(theme.corner_radius)
+                        .color(theme.control_background),
+                )
+            },
+        );
+
+        Self {
+            role: Role::AlertDialog,
+            title: Some(title.into()),
+            cancel,
+            default,
+            ..Self::new(child, is_presented, any_view(alert))
+        }
+    }
+}
+
+impl<V, B, S> View for Sheet<V, B, S>
+where
+    V: View,
+    B: Binding<bool>,
+    S: View,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        if !*self.is_presented.get(cx) {
+            self.child.process(event, id.child(&0), cx, actions);
+            return;
+        }
+
+        let offset = cx.layout.get(&id).map(|b| b.offset).unwrap_or_default();
+        cx.process_access_node(id, event, |cx| {
+            self.sheet
+                .process(&event.offset(-offset), id.child(&1), cx, actions)
+        });
+
+        // Nothing underneath gets input, including hotkeys. Tab is left
+        // for moving focus within the sheet.
+        match event {
+            Event::TouchBegin { .. } | Event::TouchMove { .. } | Event::TouchEnd { .. } => (),
+            Event::KeyDown { key, .. } => {
+                if !cx.key_handled {
+                    match key {
+                        Key::Escape => self.dismiss(cx, &self.cancel),
+                        Key::Enter if self.default.is_some() => self.dismiss(cx, &self.default),
+                        Key::Tab => return,
+                        _ => (),
+                    }
+                }
+                cx.set_handled();
+            }
+            _ if event.is_keyboard() => cx.set_handled(),
+            _ => self.child.process(event, id.child(&0), cx, actions),
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args);
+
+        if *self.is_presented.get(args.cx) {
+            let layout = args.cx.layout[&id];
+            let paint = args.vger.color_paint(DIM_COLOR);
+            args.vger.fill_rect(layout.rect, 0.0, paint);
+
+            args.vger.save();
+            args.vger.translate(layout.offset);
+            self.sheet.draw(id.child(&1), args);
+            args.vger.restore();
+        }
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let sz = self.child.layout(id.child(&0), args);
+
+        // Center the sheet over the content.
+        let mut offset = LocalOffset::zero();
+        if *self.is_presented.get(args.cx) {
+            let sheet_sz = self.sheet.layout(id.child(&1), &mut args.size(sz));
+            offset = ((sz - sheet_sz) / 2.0).into();
+        }
+
+        args.cx.layout.insert(
+            id,
+            LayoutBox {
+                rect: LocalRect::new(LocalPoint::zero(), sz),
+                offset,
+            },
+        );
+
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+        if *self.is_presented.get(cx) {
+            cx.set_access_bounds(id, xform);
+            let offset = cx.layout[&id].offset;
+            self.sheet
+                .dirty(id.child(&1), xform.pre_translate(offset), cx);
+        }
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        if !*self.is_presented.get(cx) {
+            return self.child.hittest(id.child(&0), pt, cx);
+        }
+        let layout = cx.layout.get(&id).copied().unwrap_or_default();
+        if layout.rect.contains(pt) {
+            self.sheet
+                .hittest(id.child(&1), pt - layout.offset, cx)
+                .or(Some(id))
+        } else {
+            None
+        }
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        if *self.is_presented.get(cx) {
+            // Keep Tab within the sheet.
+            let mut trap = vec![];
+            self.sheet.focus_chain(id.child(&1), cx, &mut trap);
+            chain.extend_from_slice(&trap);
+            cx.focus_trap = Some(trap);
+        } else {
+            self.child.focus_chain(id.child(&0), cx, chain)
+        }
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map);
+        if *self.is_presented.get(cx) {
+            self.sheet.gc(id.child(&1), cx, map);
+        }
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        if !*self.is_presented.get(cx) {
+            return self.child.access(id.child(&0), cx, nodes);
+        }
+
+        // While presented, only the dialog is accessible.
+        let (sheet_aid, actions) =
+            cx.access_children(id, |cx| self.sheet.access(id.child(&1), cx, nodes));
+        let aid = id.access_id();
+        let mut builder = cx.access_node(id, self.role);
+        for action in actions {
+            builder.add_action(action);
+        }
+        if let Some(title) = &self.title {
+            builder.set_name(title.as_str());
+        }
+        builder.set_children(sheet_aid.into_iter().collect::<Vec<_>>());
+        nodes.push((aid, builder));
+        Some(aid)
+    }
+}
+
+impl<V, B, S> private::Sealed for Sheet<V, B, S> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    struct Doc {
+        saving: bool,
+        count: i32,
+    }
+
+    make_lens!(DocSaving, Doc, bool, saving);
+
+    fn key_down(key: Key) -> Event {
+        Event::KeyDown {
+            key,
+            mods: Default::default(),
+            repeat: false,
+        }
+    }
+
+    #[test]
+    fn test_alert() {
+        let mut cx = Context::new();
+
+        let ui = state(
+            || Doc {
+                saving: true,
+                count: 0,
+            },
+            |s, _| {
+                rectangle()
+                    .tap(move |cx| cx[s].count += 100)
+                    .focusable()
+                    .alert(
+                        bind(s, DocSaving {}),
+                        "Save changes?",
+                        "Your changes will be lost.",
+                        vec![
+                            AlertButton::cancel("Cancel", move |cx| cx[s].count += 1),
+                            AlertButton::default_action("Save", move |cx| cx[s].count += 10),
+                        ],
+                    )
+            },
+        );
+        let s = StateHandle::<Doc>::new(cx.root_id);
+        let sz = [200.0, 200.0].into();
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz,
+                cx: &mut cx,
+                text_bounds: &mut |_, _, _| LocalRect::new(LocalPoint::zero(), [20.0, 10.0].into()),
+            },
+        );
+
+        // Tab only reaches the alert's buttons.
+        let chain = cx.update_focus(&ui);
+        assert_eq!(chain.len(), 2);
+        cx.process(&ui, &key_down(Key::Tab));
+        cx.process(&ui, &key_down(Key::Tab));
+        cx.process(&ui, &key_down(Key::Tab));
+        assert_eq!(cx.focused_id, Some(chain[0]));
+
+        // Touches don't reach the content underneath.
+        let position = [1.0, 1.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        cx.process(&ui, &Event::TouchEnd { id: 0, position });
+        assert_eq!(cx[s].count, 0);
+
+        cx.process(&ui, &key_down(Key::Enter));
+        assert_eq!((cx[s].saving, cx[s].count), (false, 10));
+
+        cx[s].saving = true;
+        cx.process(&ui, &key_down(Key::Escape));
+        assert_eq!((cx[s].saving, cx[s].count), (false, 11));
+
+        // Dismissed, so the content gets input again.
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        cx.process(&ui, &Event::TouchEnd { id: 0, position });
+        assert_eq!(cx[s].count, 111);
+    }
+
+    #[test]
+    fn test_sheet_access() {
+        let mut cx = Context::new();
+        let ui = state(
+            || true,
+            |shown, _| rectangle().role(Role::Button).sheet(shown, text("sheet")),
+        );
+
+        let mut nodes = vec![];
+        let aid = ui.access(cx.root_id, &mut cx, &mut nodes).unwrap();
+        let (_, node) = nodes.iter().find(|(id, _)| *id == aid).unwrap();
+        assert_eq!(node.role(), Role::Dialog);
+        assert!(nodes.iter().all(|(_, node)| node.role() != Role::Button));
+    }
+
+    #[test]
+    fn test_sheet_covers_root() {
+        let mut cx = Context::new();
+        let ui = state(
+            || true,
+            |shown, _| hstack((rectangle(), rectangle())).sheet(shown, text("sheet")),
+        );
+        let sz = [300.0, 200.0].into();
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz,
+                cx: &mut cx,
+                text_bounds: &mut |_, _, _| LocalRect::new(LocalPoint::zero(), [20.0, 10.0].into()),
+            },
+        );
+
+        // Attached to the root, the sheet spans the window with the
+        // dialog in the middle.
+        let id = cx.root_id.child(&0);
+        let layout = cx.layout[&id];
+        assert_eq!(layout.rect, LocalRect::new(LocalPoint::zero(), sz));
+        assert_eq!(layout.offset, [140.0, 95.0].into());
+
+        // The corners of the window hit the sheet rather than the content.
+        for pt in [[1.0, 1.0], [299.0, 199.0]] {
+            assert_eq!(ui.hittest(cx.root_id, pt.into(), &mut cx), Some(id));
+        }
+    }
+}
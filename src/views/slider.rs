@@ -1,19 +1,45 @@
 use crate::*;
+use accesskit::{Action, ActionData, Role};
 
 const SLIDER_WIDTH: f32 = 4.0;
 const SLIDER_THUMB_RADIUS: f32 = 10.0;
 
-#[derive(Clone, Copy)]
-pub struct SliderOptions {
-    thumb: Color,
+/// How much assistive technologies change a 0 to 1 value by.
+pub(crate) const ACCESS_STEP: f32 = 0.1;
+
+/// Makes a view a slider which assistive technologies can read,
+/// increment, decrement and set, for a 0 to 1 value.
+pub(crate) fn access_adjustable(
+    view: impl View,
+    get: impl Fn(&Context) -> f32 + Copy + 'static,
+    set: impl Fn(&mut Context, f32) + Copy + 'static,
+) -> impl View {
+    let slider = view
+        .on_access_action(Action::Increment, move |cx, _| {
+            set(cx, (get(cx) + ACCESS_STEP).min(1.0))
+        })
+        .on_access_action(Action::Decrement, move |cx, _| {
+            set(cx, (get(cx) - ACCESS_STEP).max(0.0))
+        })
+        .on_access_action(Action::SetValue, move |cx, request| {
+            if let Some(ActionData::NumericValue(v)) = request.data {
+                set(cx, (v as f32).clamp(0.0, 1.0))
+            }
+        })
+        .role(Role::Slider);
+
+    AccessibilityView::with(slider, move |cx, builder| {
+        builder.set_numeric_value(get(cx) as f64);
+        builder.set_min_numeric_value(0.0);
+        builder.set_max_numeric_value(1.0);
+        builder.set_numeric_value_step(ACCESS_STEP as f64);
+    })
 }
 
-impl Default for SliderOptions {
-    fn default() -> Self {
-        Self {
-            thumb: AZURE_HIGHLIGHT,
-        }
-    }
+#[derive(Clone, Copy, Default)]
+pub struct SliderOptions {
+    /// Thumb color, if not the theme's accent color.
+    thumb: Option<Color>,
 }
 
 pub trait SliderMods: View + Sized {
@@ -27,17 +53,18 @@ pub fn hslider(value: impl Binding<f32>) -> impl SliderMods {
             || 0.0,
             move |width, cx| {
                 let w = cx[width];
-                canvas(move |cx, sz, vger| {
+                let slider = canvas(move |cx, sz, vger| {
                     let c = sz.center();
 
                     let w = cx[width];
-                    let v = value.get(cx);
+                    let theme = cx.theme();
+                    let v = value.try_get(cx).copied().unwrap_or_default();
                     let r = SLIDER_THUMB_RADIUS;
                     let start_x = r;
                     let end_x = w - r;
                     let x = (1.0 - v) * start_x + v * (end_x);
 
-                    let paint = vger.color_paint(BUTTON_BACKGROUND_COLOR);
+                    let paint = vger.color_paint(theme.button_color);
                     vger.fill_rect(
                         euclid::rect(
                             start_x,
@@ -48,13 +75,13 @@ pub fn hslider(value: impl Binding<f32>) -> impl SliderMods {
                         0.0,
                         paint,
                     );
-                    let paint = vger.color_paint(AZURE_HIGHLIGHT_BACKGROUND);
+                    let paint = vger.color_paint(theme.accent_background);
                     vger.fill_rect(
                         euclid::rect(start_x, c.y - SLIDER_WIDTH / 2.0, x, SLIDER_WIDTH),
                         0.0,
                         paint,
                     );
-                    let paint = vger.color_paint(opts.thumb);
+                    let paint = vger.color_paint(opts.thumb.unwrap_or(theme.accent_color));
                     vger.fill_circle([x, c.y], r, paint);
                 })
                 .geom(move |cx, sz, _| {
@@ -64,10 +91,17 @@ pub fn hslider(value: impl Binding<f32>) -> impl SliderMods {
                 })
                 .drag_s(value, move |v, delta, _, _| {
                     *v = (*v + delta.x / w).clamp(0.0, 1.0)
-                })
+                });
+                access_adjustable(
+                    slider,
+                    move |cx| value.try_get(cx).copied().unwrap_or_default(),
+                    move |cx, v| {
+                        value.try_with_mut(cx, |value| *value = v);
+                    },
+                )
+                .focusable()
             },
         )
-        .role(accesskit::Role::Slider)
     })
 }
 
@@ -77,7 +111,7 @@ where
 {
     fn thumb_color(self, color: Color) -> Self {
         let mut opts = self.value;
-        opts.thumb = color;
+        opts.thumb = Some(color);
         ModView {
             func: self.func,
             value: opts,
@@ -94,17 +128,18 @@ pub fn vslider(
         state(
             || 0.0,
             move |height, _| {
-                canvas(move |cx, sz, vger| {
+                let slider = canvas(move |cx, sz, vger| {
                     let h = cx[height];
                     let y = value * h;
                     let c = sz.center();
-                    let paint = vger.color_paint(BUTTON_BACKGROUND_COLOR);
+                    let theme = cx.theme();
+                    let paint = vger.color_paint(theme.button_color);
                     vger.fill_rect(
                         euclid::rect(c.x - SLIDER_WIDTH / 2.0, 0.0, SLIDER_WIDTH, sz.height()),
                         0.0,
                         paint,
                     );
-                    let paint = vger.color_paint(opts.thumb);
+                    let paint = vger.color_paint(opts.thumb.unwrap_or(theme.accent_color));
                     vger.fill_circle([c.x, y], SLIDER_THUMB_RADIUS, paint);
                 })
                 .geom(move |cx, sz, _| {
@@ -114,7 +149,8 @@ pub fn vslider(
                 })
                 .drag(move |cx, delta, _, _| {
                     (set_value)(cx, (value + delta.y / cx[height]).clamp(0.0, 1.0));
-                })
+                });
+                access_adjustable(slider, move |_| value, set_value)
             },
         )
     })
@@ -42,12 +42,14 @@ impl<VT: ViewTuple + 'static, D: StackDirection + 'static> View for Stack<VT, D>
         cx: &mut Context,
         actions: &mut Vec<Box<dyn Any>>,
     ) {
-        let mut c = 0;
-        self.children.foreach_view(&mut |child| {
-            let child_id = id.child(&c);
-            let offset = cx.layout.entry(child_id).or_default().offset;
-            (*child).process(&event.offset(-offset), child_id, cx, actions);
-            c += 1;
+        cx.process_access_node(id, event, |cx| {
+            let mut c = 0;
+            self.children.foreach_view(&mut |child| {
+                let child_id = id.child(&c);
+                let offset = cx.layout.entry(child_id).or_default().offset;
+                (*child).process(&event.offset(-offset), child_id, cx, actions);
+                c += 1;
+            })
         })
     }
 
@@ -82,7 +84,7 @@ impl<VT: ViewTuple + 'static, D: StackDirection + 'static> View for Stack<VT, D>
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
         let n = self.children.len() as f32;
 
-        match D::ORIENTATION {
+        let sz = match D::ORIENTATION {
             StackOrientation::Horizontal => {
                 let proposed_child_size = LocalSize::new(args.sz.width / n, args.sz.height);
 
@@ -191,10 +193,13 @@ impl<VT: ViewTuple + 'static, D: StackDirection + 'static> View for Stack<VT, D>
                 });
                 args.sz
             }
-        }
+        };
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
     }
 
     fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        cx.set_access_bounds(id, xform);
         let mut c = 0;
         self.children.foreach_view(&mut |child| {
             let child_id = id.child(&c);
@@ -229,6 +234,14 @@ impl<VT: ViewTuple + 'static, D: StackDirection + 'static> View for Stack<VT, D>
         });
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        let mut c = 0;
+        self.children.foreach_view(&mut |child| {
+            child.focus_chain(id.child(&c), cx, chain);
+            c += 1;
+        });
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         map.push(id);
         let mut c = 0;
@@ -244,19 +257,25 @@ impl<VT: ViewTuple + 'static, D: StackDirection + 'static> View for Stack<VT, D>
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
-        let mut c = 0;
-        let mut builder = accesskit::NodeBuilder::new(accesskit::Role::List);
-        let mut children = vec![];
-        self.children.foreach_view(&mut |child| {
-            if let Some(id) = child.access(id.child(&c), cx, nodes) {
-                children.push(id)
-            }
-            c += 1;
+        let mut builder = cx.access_node(id, accesskit::Role::List);
+        let (children, actions) = cx.access_children(id, |cx| {
+            let mut c = 0;
+            let mut children = vec![];
+            self.children.foreach_view(&mut |child| {
+                if let Some(id) = child.access(id.child(&c), cx, nodes) {
+                    children.push(id)
+                }
+                c += 1;
+            });
+            children
         });
+        for action in actions {
+            builder.add_action(action);
+        }
         builder.set_children(children);
-        nodes.push((id.access_id(), builder.build(&mut cx.access_node_classes)));
+        nodes.push((id.access_id(), builder));
         Some(id.access_id())
     }
 }
@@ -26,6 +26,12 @@ impl<S: 'static> StateHandle<S> {
         }
     }
 
+    /// ID of the `state` view, for identifying the UI that owns the
+    /// state, e.g. when presenting overlays.
+    pub fn id(&self) -> ViewId {
+        self.id
+    }
+
     /// Makes it convenient to get a function to set the value.
     pub fn setter(self) -> impl Fn(S, &mut Context) {
         move |s, cx| cx[self] = s
@@ -125,7 +131,7 @@ where
             dirty: false,
         });
 
-        if holder.dirty {
+        if holder.dirty && !cx.in_access_bounds_pass {
             // Add a region.
             let rect = cx.layout[&id].rect;
             let pts: [LocalPoint; 4] = [
@@ -151,6 +157,11 @@ where
         (self.func)(StateHandle::new(id), cx).commands(id.child(&0), cx, cmds);
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        cx.init_state(id, &self.default);
+        (self.func)(StateHandle::new(id), cx).focus_chain(id.child(&0), cx, chain);
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
         cx.init_state(id, &self.default);
         map.push(id);
@@ -161,7 +172,7 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
         cx.init_state(id, &self.default);
         (self.func)(StateHandle::new(id), cx).access(id.child(&0), cx, nodes)
@@ -45,7 +45,14 @@ where
                     actions.push(Box::new((self.func)(cx)))
                 }
             }
-            _ => (),
+            _ => {
+                // Assistive technologies press with the default action.
+                if let Some(request) = cx.access_request(event) {
+                    if request.action == accesskit::Action::Default {
+                        actions.push(Box::new((self.func)(cx)))
+                    }
+                }
+            }
         }
     }
 
@@ -54,7 +61,13 @@ where
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        self.child.layout(id.child(&0), args)
+        let sz = self.child.layout(id.child(&0), args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
     }
 
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
@@ -65,7 +78,12 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
         self.child.gc(id.child(&0), cx, map)
     }
 
@@ -73,8 +91,10 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
+        cx.access_actions.push(accesskit::Action::Default);
+        cx.audit_interactive(id);
         self.child.access(id.child(&0), cx, nodes)
     }
 }
@@ -123,7 +143,13 @@ where
                     actions.push(Box::new(self.action.clone()))
                 }
             }
-            _ => (),
+            _ => {
+                if let Some(request) = cx.access_request(event) {
+                    if request.action == accesskit::Action::Default {
+                        actions.push(Box::new(self.action.clone()))
+                    }
+                }
+            }
         }
     }
 
@@ -132,7 +158,13 @@ where
     }
 
     fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        self.child.layout(id.child(&0), args)
+        let sz = self.child.layout(id.child(&0), args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
     }
 
     fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
@@ -143,7 +175,12 @@ where
         self.child.commands(id.child(&0), cx, cmds)
     }
 
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
     fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
         self.child.gc(id.child(&0), cx, map)
     }
 
@@ -151,8 +188,10 @@ where
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
+        cx.access_actions.push(accesskit::Action::Default);
+        cx.audit_interactive(id);
         self.child.access(id.child(&0), cx, nodes)
     }
 }
@@ -9,8 +9,10 @@ pub trait TextModifiers: View + Sized {
 #[derive(Clone)]
 pub struct Text {
     text: String,
-    size: u32,
-    color: Color,
+    /// Font size, if not the theme's.
+    size: Option<u32>,
+    /// Color, if not the theme's.
+    color: Option<Color>,
 }
 
 impl Text {
@@ -19,23 +21,35 @@ impl Text {
         Text {
             text: self.text,
             size: self.size,
-            color,
+            color: Some(color),
         }
     }
 }
 
 impl View for Text {
     fn draw(&self, _id: ViewId, args: &mut DrawArgs) {
+        let theme = args.cx.theme();
+        let size = self.size.unwrap_or(theme.font_size);
+        let color = self.color.unwrap_or(theme.text_color);
         let vger = &mut args.vger;
-        let origin = vger.text_bounds(self.text.as_str(), self.size, None).origin;
+        let origin = vger.text_bounds(self.text.as_str(), size, None).origin;
 
         vger.save();
         vger.translate([-origin.x, -origin.y]);
-        vger.text(self.text.as_str(), self.size, self.color, None);
+        vger.text(self.text.as_str(), size, color, None);
         vger.restore();
     }
-    fn layout(&self, _id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        (args.text_bounds)(self.text.as_str(), self.size, None).size
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let size = self.size.unwrap_or(args.cx.theme().font_size);
+        let sz = (args.text_bounds)(self.text.as_str(), size, None).size;
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
+    }
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        cx.set_access_bounds(id, xform);
+    }
+    fn gc(&self, id: ViewId, _cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
     }
     fn hittest(&self, _id: ViewId, _pt: LocalPoint, _cx: &mut Context) -> Option<ViewId> {
         None
@@ -45,12 +59,16 @@ impl View for Text {
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
+        let theme = cx.theme();
+        let color = self.color.unwrap_or(theme.text_color);
+        cx.audit_text(&self.text, color, self.size.unwrap_or(theme.font_size));
+
         let aid = id.access_id();
-        let mut builder = accesskit::NodeBuilder::new(accesskit::Role::LabelText);
+        let mut builder = cx.access_node(id, accesskit::Role::LabelText);
         builder.set_name(self.text.clone());
-        nodes.push((aid, builder.build(&mut cx.access_node_classes)));
+        nodes.push((aid, builder));
         Some(aid)
     }
 }
@@ -60,14 +78,14 @@ impl TextModifiers for Text {
         Self {
             text: self.text,
             color: self.color,
-            size,
+            size: Some(size),
         }
     }
     fn color(self, color: Color) -> Text {
         Text {
             text: self.text,
             size: self.size,
-            color,
+            color: Some(color),
         }
     }
 }
@@ -78,8 +96,8 @@ impl private::Sealed for Text {}
 pub fn text(name: &str) -> Text {
     Text {
         text: String::from(name),
-        size: Text::DEFAULT_SIZE,
-        color: TEXT_COLOR,
+        size: None,
+        color: None,
     }
 }
 
@@ -87,32 +105,26 @@ impl<V> View for V
 where
     V: std::fmt::Display + std::fmt::Debug + 'static,
 {
-    fn draw(&self, _id: ViewId, args: &mut DrawArgs) {
-        let txt = &format!("{}", self);
-        let vger = &mut args.vger;
-        let origin = vger.text_bounds(txt, Text::DEFAULT_SIZE, None).origin;
-
-        vger.save();
-        vger.translate([-origin.x, -origin.y]);
-        vger.text(txt, Text::DEFAULT_SIZE, TEXT_COLOR, None);
-        vger.restore();
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        text(&format!("{}", self)).draw(id, args)
+    }
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        text(&format!("{}", self)).layout(id, args)
     }
-    fn layout(&self, _id: ViewId, args: &mut LayoutArgs) -> LocalSize {
-        let txt = &format!("{}", self);
-        (args.text_bounds)(txt, Text::DEFAULT_SIZE, None).size
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        cx.set_access_bounds(id, xform);
+    }
+    fn gc(&self, id: ViewId, _cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
     }
 
     fn access(
         &self,
         id: ViewId,
         cx: &mut Context,
-        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
     ) -> Option<accesskit::NodeId> {
-        let aid = id.access_id();
-        let mut builder = accesskit::NodeBuilder::new(accesskit::Role::LabelText);
-        builder.set_name(format!("{}", self));
-        nodes.push((aid, builder.build(&mut cx.access_node_classes)));
-        Some(aid)
+        text(&format!("{}", self)).access(id, cx, nodes)
     }
 }
 
@@ -123,15 +135,15 @@ where
     fn font_size(self, size: u32) -> Text {
         Text {
             text: format!("{}", self),
-            size,
-            color: TEXT_COLOR,
+            size: Some(size),
+            color: None,
         }
     }
     fn color(self, color: Color) -> Text {
         Text {
             text: format!("{}", self),
-            size: Text::DEFAULT_SIZE,
-            color,
+            size: None,
+            color: Some(color),
         }
     }
 }
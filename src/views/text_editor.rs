@@ -1,10 +1,22 @@
 use crate::*;
 
+/// Byte offset of the character at `index`, or the end of the string.
+pub(crate) fn byte_offset(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map(|(i, _)| i)
+        .unwrap_or(text.len())
+}
+
 /// View-model for `text_editor`.
 struct TextEditorState {
+    /// Cursor position in characters (not bytes).
     cursor: usize,
     glyph_rects: Vec<LocalRect>,
     lines: Vec<LineMetrics>,
+
+    /// Text being composed by an input method.
+    preedit: String,
 }
 
 impl TextEditorState {
@@ -78,7 +90,7 @@ impl TextEditorState {
                 text
             }
             Key::ArrowRight => {
-                self.fwd(text.len());
+                self.fwd(text.chars().count());
                 text
             }
             Key::ArrowUp => {
@@ -92,36 +104,32 @@ impl TextEditorState {
             Key::Backspace => {
                 if self.cursor > 0 {
                     let mut t = text;
-                    t.remove(self.cursor - 1);
+                    t.remove(byte_offset(&t, self.cursor - 1));
                     self.back();
                     t
                 } else {
                     text
                 }
             }
-            Key::Character(c) => {
-                let mut t = text;
-                t.insert_str(self.cursor, &format!("{}", c));
-                self.cursor += 1;
-                t
-            }
-            Key::Space => {
-                let mut t = text;
-                t.insert(self.cursor, ' ');
-                self.cursor += 1;
-                t
-            }
             Key::Home => {
                 self.cursor = 0;
                 text
             }
             Key::End => {
-                self.cursor = text.len();
+                self.cursor = text.chars().count();
                 text
             }
             _ => text,
         }
     }
+
+    /// Inserts typed or committed text at the cursor.
+    fn input(&mut self, s: &str, text: String) -> String {
+        let mut t = text;
+        t.insert_str(byte_offset(&t, self.cursor), s);
+        self.cursor += s.chars().count();
+        t
+    }
 }
 
 impl TextEditorState {
@@ -130,6 +138,7 @@ impl TextEditorState {
             cursor: 0,
             glyph_rects: vec![],
             lines: vec![],
+            preedit: String::new(),
         }
     }
 }
@@ -144,36 +153,66 @@ pub fn text_editor(text: impl Binding<String>) -> impl View {
             let cursor = cx[state].cursor;
             canvas(move |cx, rect, vger| {
                 vger.translate([0.0, rect.height()]);
-                let font_size = 18;
+                let theme = cx.theme();
+                let font_size = theme.font_size;
                 let break_width = Some(rect.width());
 
-                vger.text(text.get(cx), font_size, TEXT_COLOR, break_width);
+                // Show any IME composition inline at the cursor.
+                let preedit_len = cx[state].preedit.chars().count();
+                let mut display = text.get(cx).clone();
+                display.insert_str(byte_offset(&display, cursor), &cx[state].preedit);
+
+                vger.text(&display, font_size, theme.text_color, break_width);
 
                 if has_focus {
-                    let rects = vger.glyph_positions(text.get(cx), font_size, break_width);
-                    let lines = vger.line_metrics(text.get(cx), font_size, break_width);
+                    let rects = vger.glyph_positions(&display, font_size, break_width);
+                    let lines = vger.line_metrics(&display, font_size, break_width);
+
+                    let underline_paint = vger.color_paint(theme.text_color);
+                    for r in rects.iter().skip(cursor).take(preedit_len) {
+                        vger.fill_rect(
+                            euclid::rect(r.min_x(), r.min_y() - 2.0, r.width(), 1.0),
+                            0.0,
+                            underline_paint,
+                        );
+                    }
+
                     let glyph_rect_paint = vger.color_paint(vger::Color::MAGENTA);
-                    let p = if cursor == rects.len() {
-                        if let Some(r) = rects.last() {
-                            [r.origin.x + r.size.width, r.origin.y].into()
-                        } else {
-                            [0.0, -20.0].into()
-                        }
-                    } else {
-                        rects[cursor].origin
+                    let caret = cursor + preedit_len;
+                    let p = match rects.get(caret) {
+                        Some(r) => r.origin,
+                        None => match rects.last() {
+                            Some(r) => [r.origin.x + r.size.width, r.origin.y].into(),
+                            None => [0.0, -20.0].into(),
+                        },
                     };
-                    vger.fill_rect(LocalRect::new(p, [2.0, 20.0].into()), 0.0, glyph_rect_paint);
+                    let caret_rect = LocalRect::new(p, [2.0, 20.0].into());
+                    vger.fill_rect(caret_rect, 0.0, glyph_rect_paint);
+                    cx.ime_rect =
+                        Some(vger.current_transform().outer_transformed_rect(&caret_rect));
 
-                    cx[state].glyph_rects = rects;
-                    cx[state].lines = lines;
+                    if preedit_len == 0 {
+                        cx[state].glyph_rects = rects;
+                        cx[state].lines = lines;
+                    }
                 }
             })
-            .key(move |cx, k| {
-                if has_focus {
+            .key_down(move |cx, k, _, _| {
+                let t = text.with(cx, |t| t.clone());
+                let new_t = cx[state].key(&k, t);
+                text.with_mut(cx, |t| *t = new_t);
+            })
+            .text_input(move |cx, event| match event {
+                Event::TextInput(s) | Event::ImeCommit(s) => {
+                    cx[state].preedit.clear();
                     let t = text.with(cx, |t| t.clone());
-                    let new_t = cx[state].key(&k, t);
+                    let new_t = cx[state].input(s, t);
                     text.with_mut(cx, |t| *t = new_t);
                 }
+                Event::ImePreedit { text, .. } => {
+                    cx[state].preedit = text.clone();
+                }
+                _ => (),
             })
         })
     })
@@ -0,0 +1,638 @@
+use crate::*;
+use accesskit::{Action, ActionData, Role};
+use std::any::Any;
+use std::rc::Rc;
+
+const TEXT_FIELD_PADDING: f32 = 5.0;
+
+type TextFieldCallback = Option<Rc<dyn Fn(&mut Context)>>;
+
+/// Options for `text_field`. Set these with the `TextFieldMods` modifiers.
+#[derive(Clone, Default)]
+pub struct TextFieldOptions {
+    placeholder: String,
+    secure: bool,
+    numeric: bool,
+    max_length: Option<usize>,
+    on_submit: TextFieldCallback,
+    on_commit: TextFieldCallback,
+}
+
+impl TextFieldOptions {
+    /// Should the character be inserted?
+    fn accepts(&self, c: char) -> bool {
+        !self.numeric || c.is_ascii_digit() || c == '.' || c == '-'
+    }
+
+    /// Text shown in place of the actual string.
+    fn display(&self, text: &str) -> String {
+        if self.secure {
+            "•".repeat(text.chars().count())
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// View-model for `text_field`.
+struct TextFieldState {
+    /// Cursor position in characters (not bytes).
+    cursor: usize,
+
+    /// Horizontal scroll so the cursor stays visible.
+    scroll: f32,
+
+    /// Did we have the focus last time we checked?
+    had_focus: bool,
+
+    /// Text being composed by an input method.
+    preedit: String,
+
+    /// Byte range of the IME's cursor within `preedit`.
+    preedit_cursor: Option<(usize, usize)>,
+}
+
+impl TextFieldState {
+    fn new() -> Self {
+        Self {
+            cursor: 0,
+            scroll: 0.0,
+            had_focus: false,
+            preedit: String::new(),
+            preedit_cursor: None,
+        }
+    }
+
+    fn insert(&mut self, c: char, text: String, opts: &TextFieldOptions) -> String {
+        let len = text.chars().count();
+        if !opts.accepts(c) || matches!(opts.max_length, Some(max) if len >= max) {
+            return text;
+        }
+        let mut t = text;
+        t.insert(byte_offset(&t, self.cursor), c);
+        self.cursor += 1;
+        t
+    }
+
+    /// Inserts typed or committed text, dropping filtered characters.
+    fn input(&mut self, s: &str, text: String, opts: &TextFieldOptions) -> String {
+        s.chars().fold(text, |t, c| self.insert(c, t, opts))
+    }
+
+    /// Text to show, with any IME composition spliced in at the cursor.
+    /// Both are masked for secure fields.
+    fn display(&self, text: &str, opts: &TextFieldOptions) -> String {
+        let mut display = opts.display(text);
+        display.insert_str(
+            byte_offset(&display, self.cursor),
+            &opts.display(&self.preedit),
+        );
+        display
+    }
+
+    /// First and last glyphs of the IME composition in the displayed
+    /// text, if there is one.
+    fn preedit_glyphs(&self) -> Option<(usize, usize)> {
+        let len = self.preedit.chars().count();
+        if len > 0 {
+            Some((self.cursor, self.cursor + len - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Caret position in characters of the displayed text.
+    fn caret(&self) -> usize {
+        let end = self.preedit_cursor.map_or(self.preedit.len(), |c| c.0);
+        self.cursor + self.preedit.get(..end).map_or(0, |s| s.chars().count())
+    }
+
+    /// Applies a key press. Returns the new text.
+    fn key(&mut self, k: &Key, text: String) -> String {
+        let len = text.chars().count();
+        self.cursor = self.cursor.min(len);
+        match k {
+            Key::ArrowLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+                text
+            }
+            Key::ArrowRight => {
+                self.cursor = (self.cursor + 1).min(len);
+                text
+            }
+            Key::Home | Key::ArrowUp => {
+                self.cursor = 0;
+                text
+            }
+            Key::End | Key::ArrowDown => {
+                self.cursor = len;
+                text
+            }
+            Key::Backspace => {
+                if self.cursor > 0 {
+                    let mut t = text;
+                    t.remove(byte_offset(&t, self.cursor - 1));
+                    self.cursor -= 1;
+                    t
+                } else {
+                    text
+                }
+            }
+            Key::Delete => {
+                if self.cursor < len {
+                    let mut t = text;
+                    t.remove(byte_offset(&t, self.cursor));
+                    t
+                } else {
+                    text
+                }
+            }
+            _ => text,
+        }
+    }
+}
+
+/// Exposes the field's value to assistive technologies.
+struct TextFieldAccess<V, B> {
+    child: V,
+    text: B,
+    secure: bool,
+    placeholder: String,
+}
+
+impl<V, B> View for TextFieldAccess<V, B>
+where
+    V: View,
+    B: Binding<String>,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        cx.process_access_node(id, event, |cx| {
+            if let Some(request) = cx.access_request(event) {
+                if let (Action::SetValue, Some(ActionData::Value(value))) =
+                    (request.action, &request.data)
+                {
+                    self.text.try_with_mut(cx, |text| *text = value.to_string());
+                }
+            }
+            self.child.process(event, id.child(&0), cx, actions)
+        });
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args);
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        let sz = self.child.layout(id.child(&0), args);
+        args.cx.layout.entry(id).or_default().rect = LocalRect::new(LocalPoint::zero(), sz);
+        sz
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        cx.set_access_bounds(id, xform);
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        let (_, actions) = cx.access_children(id, |cx| self.child.access(id.child(&0), cx, nodes));
+        let aid = id.access_id();
+        let mut builder = cx.access_node(id, Role::TextField);
+        for action in actions {
+            builder.add_action(action);
+        }
+        builder.add_action(Action::SetValue);
+        if self.secure {
+            builder.set_protected();
+        } else {
+            builder.set_value(self.text.try_get(cx).cloned().unwrap_or_default());
+        }
+        if !self.placeholder.is_empty() {
+            builder.set_placeholder(self.placeholder.clone());
+        }
+        nodes.push((aid, builder));
+        Some(aid)
+    }
+}
+
+impl<V, B> private::Sealed for TextFieldAccess<V, B> {}
+
+pub trait TextFieldMods: View + Sized {
+    /// Text shown in gray while the field is empty.
+    fn placeholder(self, text: &str) -> Self;
+
+    /// Masks the contents, for passwords.
+    fn secure(self) -> Self;
+
+    /// Only allows digits, '.' and '-' to be entered.
+    fn numeric(self) -> Self;
+
+    /// Limits the number of characters which can be entered.
+    fn max_length(self, max: usize) -> Self;
+
+    /// Called when Enter is pressed.
+    fn on_submit<F: Fn(&mut Context) + 'static>(self, f: F) -> Self;
+
+    /// Called when the field loses the keyboard focus.
+    fn on_commit<F: Fn(&mut Context) + 'static>(self, f: F) -> Self;
+}
+
+/// A single-line text field.
+///
+/// Text which doesn't fit is scrolled horizontally
+/// to keep the cursor visible.
+pub fn text_field(text: impl Binding<String>) -> impl TextFieldMods {
+    modview(move |opts: TextFieldOptions, _| {
+        let access_placeholder = opts.placeholder.clone();
+        let secure = opts.secure;
+        TextFieldAccess {
+            child: focus(move |has_focus| {
+                let opts = opts.clone();
+                state(TextFieldState::new, move |state, _| {
+                    let draw_opts = opts.clone();
+                    let key_opts = opts.clone();
+                    let input_opts = opts.clone();
+                    let commit_opts = opts.clone();
+                    canvas(move |cx, rect, vger| {
+                        let font_size = Text::DEFAULT_SIZE;
+                        let line_height = font_size as f32 + 2.0 * TEXT_FIELD_PADDING;
+                        let field = LocalRect::new(
+                            [0.0, rect.center().y - line_height / 2.0].into(),
+                            [rect.width(), line_height].into(),
+                        );
+
+                        let theme = cx.theme();
+                        let paint = vger.color_paint(theme.button_color);
+                        vger.fill_rect(field, theme.corner_radius, paint);
+                        vger.scissor(field);
+
+                        let display = text
+                            .try_with(cx, |text| cx[state].display(text, &draw_opts))
+                            .unwrap_or_default();
+                        let baseline = [
+                            field.min_x() + TEXT_FIELD_PADDING,
+                            field.min_y() + TEXT_FIELD_PADDING + font_size as f32 * 0.25,
+                        ];
+
+                        if display.is_empty() {
+                            vger.save();
+                            vger.translate(baseline);
+                            vger.text(
+                                &draw_opts.placeholder,
+                                font_size,
+                                theme.disabled_text_color,
+                                None,
+                            );
+                            vger.restore();
+                        }
+
+                        // Scroll so the cursor is visible.
+                        let rects = vger.glyph_positions(&display, font_size, None);
+                        let cursor_x = match rects.get(cx[state].caret()) {
+                            Some(r) => r.min_x(),
+                            None => rects.last().map_or(0.0, |r| r.max_x()),
+                        };
+                        let visible = field.width() - 2.0 * TEXT_FIELD_PADDING;
+                        let mut scroll = cx[state].scroll;
+                        if cursor_x - scroll > visible {
+                            scroll = cursor_x - visible;
+                        }
+                        if cursor_x < scroll {
+                            scroll = cursor_x;
+                        }
+                        cx[state].scroll = scroll;
+
+                        vger.translate([baseline[0] - scroll, baseline[1]]);
+                        vger.text(&display, font_size, theme.text_color, None);
+
+                        // Underline the IME composition.
+                        let underline = cx[state].preedit_glyphs();
+                        if let Some((Some(a), Some(b))) =
+                            underline.map(|(first, last)| (rects.get(first), rects.get(last)))
+                        {
+                            let paint = vger.color_paint(theme.text_color);
+                            vger.fill_rect(
+                                euclid::rect(a.min_x(), -3.0, b.max_x() - a.min_x(), 1.0),
+                                0.0,
+                                paint,
+                            );
+                        }
+
+                        if has_focus {
+                            let caret = LocalRect::new(
+                                [cursor_x, -(font_size as f32) * 0.25].into(),
+                                [2.0, font_size as f32].into(),
+                            );
+                            let paint = vger.color_paint(theme.accent_color);
+                            vger.fill_rect(caret, 0.0, paint);
+                            cx.ime_rect =
+                                Some(vger.current_transform().outer_transformed_rect(&caret));
+                        }
+                    })
+                    .key_down(move |cx, k, _, _| {
+                        if let Key::Enter = k {
+                            if let Some(f) = &key_opts.on_submit {
+                                f(cx);
+                                cx.set_handled();
+                            }
+                        } else {
+                            if let Some(t) = text.try_with(cx, |t| t.clone()) {
+                                let new_t = cx[state].key(&k, t.clone());
+                                if new_t != t {
+                                    text.try_with_mut(cx, |t| *t = new_t);
+                                }
+                            }
+                        }
+                    })
+                    .text_input(move |cx, event| match event {
+                        Event::TextInput(s) | Event::ImeCommit(s) => {
+                            cx[state].preedit.clear();
+                            cx[state].preedit_cursor = None;
+                            if let Some(t) = text.try_with(cx, |t| t.clone()) {
+                                let new_t = cx[state].input(s, t.clone(), &input_opts);
+                                if new_t != t {
+                                    text.try_with_mut(cx, |t| *t = new_t);
+                                }
+                            }
+                        }
+                        Event::ImePreedit {
+                            text: preedit,
+                            cursor,
+                        } => {
+                            cx[state].preedit = preedit.clone();
+                            cx[state].preedit_cursor = *cursor;
+                        }
+                        _ => (),
+                    })
+                    .anim(move |cx, _| {
+                        if cx[state].had_focus != has_focus {
+                            cx[state].had_focus = has_focus;
+                            if !has_focus {
+                                if let Some(f) = &commit_opts.on_commit {
+                                    f(cx);
+                                }
+                            }
+                        }
+                    })
+                })
+            }),
+            text,
+            secure,
+            placeholder: access_placeholder,
+        }
+    })
+}
+
+impl<F> TextFieldMods for ModView<TextFieldOptions, F>
+where
+    ModView<TextFieldOptions, F>: View,
+{
+    fn placeholder(self, text: &str) -> Self {
+        let mut opts = self.value;
+        opts.placeholder = text.into();
+        ModView {
+            func: self.func,
+            value: opts,
+        }
+    }
+
+    fn secure(self) -> Self {
+        let mut opts = self.value;
+        opts.secure = true;
+        ModView {
+            func: self.func,
+            value: opts,
+        }
+    }
+
+    fn numeric(self) -> Self {
+        let mut opts = self.value;
+        opts.numeric = true;
+        ModView {
+            func: self.func,
+            value: opts,
+        }
+    }
+
+    fn max_length(self, max: usize) -> Self {
+        let mut opts = self.value;
+        opts.max_length = Some(max);
+        ModView {
+            func: self.func,
+            value: opts,
+        }
+    }
+
+    fn on_submit<G: Fn(&mut Context) + 'static>(self, f: G) -> Self {
+        let mut opts = self.value;
+        opts.on_submit = Some(Rc::new(f));
+        ModView {
+            func: self.func,
+            value: opts,
+        }
+    }
+
+    fn on_commit<G: Fn(&mut Context) + 'static>(self, f: G) -> Self {
+        let mut opts = self.value;
+        opts.on_commit = Some(Rc::new(f));
+        ModView {
+            func: self.func,
+            value: opts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Form {
+        text: String,
+        submitted: bool,
+    }
+
+    make_lens!(FormText, Form, String, text);
+    make_lens!(FormSubmitted, Form, bool, submitted);
+
+    fn key_down(key: Key) -> Event {
+        Event::KeyDown {
+            key,
+            mods: Default::default(),
+            repeat: false,
+        }
+    }
+
+    fn type_events(ui: &impl View, cx: &mut Context, events: &[Event]) {
+        let mut actions = vec![];
+        let tap = [
+            Event::TouchBegin {
+                id: 0,
+                position: [10.0, 10.0].into(),
+            },
+            Event::TouchEnd {
+                id: 0,
+                position: [10.0, 10.0].into(),
+            },
+        ];
+        for event in tap.iter().chain(events) {
+            ui.process(event, cx.root_id, cx, &mut actions);
+        }
+    }
+
+    fn layout(ui: &impl View, cx: &mut Context) {
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz: [100.0, 30.0].into(),
+                cx,
+                text_bounds: &mut |_, _, _| LocalRect::zero(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_text_field_typing() {
+        let mut cx = Context::new();
+        let ui = state(Form::default, |s, _| {
+            text_field(bind(s, FormText {}))
+                .max_length(3)
+                .on_submit(move |cx| bind(s, FormSubmitted {}).with_mut(cx, |b| *b = true))
+        });
+        layout(&ui, &mut cx);
+
+        type_events(
+            &ui,
+            &mut cx,
+            &[
+                Event::TextInput("aé".into()),
+                key_down(Key::ArrowLeft),
+                Event::TextInput("bc".into()),
+            ],
+        );
+
+        let s = StateHandle::<Form>::new(cx.root_id);
+        assert_eq!(cx[s].text, "abé");
+        assert!(!cx[s].submitted);
+
+        type_events(
+            &ui,
+            &mut cx,
+            &[key_down(Key::Backspace), key_down(Key::Enter)],
+        );
+        assert_eq!(cx[s].text, "aé");
+        assert!(cx[s].submitted);
+    }
+
+    #[test]
+    fn test_text_field_numeric() {
+        let mut cx = Context::new();
+        let ui = state(Form::default, |s, _| {
+            text_field(bind(s, FormText {})).numeric()
+        });
+        layout(&ui, &mut cx);
+
+        type_events(
+            &ui,
+            &mut cx,
+            &[
+                Event::TextInput("1x.".into()),
+                Event::TextInput(" 5".into()),
+            ],
+        );
+
+        let s = StateHandle::<Form>::new(cx.root_id);
+        assert_eq!(cx[s].text, "1.5");
+    }
+
+    #[test]
+    fn test_text_field_ime() {
+        let mut cx = Context::new();
+        let ui = state(Form::default, |s, _| text_field(bind(s, FormText {})));
+        layout(&ui, &mut cx);
+
+        type_events(
+            &ui,
+            &mut cx,
+            &[Event::ImePreedit {
+                text: "にほ".into(),
+                cursor: Some((6, 6)),
+            }],
+        );
+
+        // Composition isn't part of the value until it's committed.
+        let s = StateHandle::<Form>::new(cx.root_id);
+        assert_eq!(cx[s].text, "");
+
+        type_events(&ui, &mut cx, &[Event::ImeCommit("日本".into())]);
+        assert_eq!(cx[s].text, "日本");
+    }
+
+    #[test]
+    fn test_text_field_secure_preedit() {
+        let opts = TextFieldOptions {
+            secure: true,
+            ..Default::default()
+        };
+        let mut state = TextFieldState::new();
+        state.cursor = 1;
+        state.preedit = "にほ".into();
+        assert_eq!(state.display("ab", &opts), "••••");
+        assert_eq!(state.display("ab", &Default::default()), "aにほb");
+    }
+
+    #[test]
+    fn test_text_field_preedit_glyphs() {
+        // What an empty field has on its first draw.
+        let mut state = TextFieldState::new();
+        assert_eq!(state.preedit_glyphs(), None);
+
+        state.cursor = 1;
+        state.preedit = "にほ".into();
+        assert_eq!(state.preedit_glyphs(), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_text_field_access() {
+        let mut cx = Context::new();
+        let ui = state(
+            || String::from("hunter2"),
+            |s, _| text_field(s).placeholder("Password"),
+        );
+
+        let mut nodes = vec![];
+        let aid = ui.access(cx.root_id, &mut cx, &mut nodes).unwrap();
+        let (_, node) = nodes.iter().find(|(id, _)| *id == aid).unwrap();
+        assert_eq!(node.role(), Role::TextField);
+        assert_eq!(node.value(), Some("hunter2"));
+        assert_eq!(node.placeholder(), Some("Password"));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::*;
+use std::any::Any;
+
+/// Struct for the `text_input` modifier.
+pub struct TextInputView<V, F> {
+    child: V,
+    func: F,
+}
+
+impl<V, F> TextInputView<V, F>
+where
+    V: View,
+    F: Fn(&mut Context, &Event) + 'static,
+{
+    pub fn new(v: V, f: F) -> Self {
+        Self { child: v, func: f }
+    }
+}
+
+impl<V, F> View for TextInputView<V, F>
+where
+    V: View,
+    F: Fn(&mut Context, &Event) + 'static,
+{
+    fn process(
+        &self,
+        event: &Event,
+        vid: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        let reached = cx.key_reached;
+        self.child.process(event, vid.child(&0), cx, actions);
+        match &event {
+            Event::TextInput(_) | Event::ImePreedit { .. } | Event::ImeCommit(_)
+                if cx.is_key_target(reached) =>
+            {
+                (self.func)(cx, event)
+            }
+            _ => (),
+        }
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        self.child.access(id.child(&0), cx, nodes)
+    }
+}
+
+impl<V, F> private::Sealed for TextInputView<V, F> {}
@@ -5,22 +5,42 @@ pub fn toggle(on: impl Binding<bool>) -> impl View {
     state(
         || (),
         move |_, cx| {
-            let b = *on.get(cx);
-            zstack((
+            let b = on.try_get(cx).copied().unwrap_or_default();
+            let theme = cx.theme();
+            let switch = zstack((
                 rectangle()
                     .color(if b {
-                        AZURE_HIGHLIGHT_BACKGROUND
+                        theme.accent_background
                     } else {
-                        CONTROL_BACKGROUND
+                        theme.control_background
                     })
                     .corner_radius(10.0)
                     .size([40.0, 20.0])
-                    .tap(move |cx| on.with_mut(cx, |b| *b = !*b)),
+                    .tap(move |cx| {
+                        on.try_with_mut(cx, |b| *b = !*b);
+                    }),
                 circle()
-                    .color(if b { AZURE_HIGHLIGHT } else { MEDIUM_GRAY })
+                    .color(if b {
+                        theme.accent_color
+                    } else {
+                        theme.disabled_text_color
+                    })
                     .size([10.0, 10.0])
                     .offset([if b { 25.0 } else { 5.0 }, 5.0]),
             ))
+            .on_access_action(accesskit::Action::Default, move |cx, _| {
+                on.try_with_mut(cx, |b| *b = !*b);
+            })
+            .role(accesskit::Role::Switch);
+
+            AccessibilityView::with(switch, move |_, builder| {
+                builder.set_checked_state(if b {
+                    accesskit::CheckedState::True
+                } else {
+                    accesskit::CheckedState::False
+                })
+            })
+            .focusable()
         },
     )
 }
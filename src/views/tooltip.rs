@@ -0,0 +1,272 @@
+use crate::*;
+use std::any::Any;
+
+/// How long the pointer has to rest over a view before its tooltip shows.
+pub const TOOLTIP_DELAY: f32 = 0.5;
+
+/// Gap between the pointer and the tooltip.
+const TOOLTIP_GAP: f32 = 16.0;
+
+#[derive(Default)]
+struct TooltipState {
+    /// Seconds the pointer has rested over the view, until the tooltip shows.
+    hover: Option<f32>,
+    /// Last pointer position over the view.
+    position: LocalPoint,
+    /// Don't show again until the pointer leaves, after a press.
+    suppressed: bool,
+    /// Transform from the last draw, so we know when the view moves.
+    xform: Option<LocalToWorld>,
+}
+
+/// Struct for the `tooltip` modifier.
+pub struct Tooltip<V, T> {
+    child: V,
+    tip: T,
+    delay: f32,
+}
+
+impl<V, T> Tooltip<V, T>
+where
+    V: View,
+    T: View + Clone,
+{
+    pub fn new(child: V, tip: T) -> Self {
+        Self {
+            child,
+            tip,
+            delay: TOOLTIP_DELAY,
+        }
+    }
+
+    /// Sets how many seconds the pointer must rest before the tooltip shows.
+    pub fn delay(self, seconds: f32) -> Self {
+        Self {
+            delay: seconds,
+            ..self
+        }
+    }
+
+    fn show(&self, id: ViewId, cx: &mut Context) {
+        let s = tooltip_state(cx, id);
+        let xform = match s.xform {
+            Some(xform) => xform,
+            None => return,
+        };
+        let pt = xform.transform_point(s.position);
+        let anchor = WorldRect::new([pt.x, pt.y - TOOLTIP_GAP].into(), [0.0, TOOLTIP_GAP].into());
+
+        let theme = cx.theme();
+        let tip = self.tip.clone().padding(theme.spacing).background(
+            rectangle()
+                .corner_radius(theme.corner_radius)
+                .color(theme.control_background),
+        );
+        cx.present_passive_overlay(id, anchor, tip);
+    }
+
+    fn hide(&self, id: ViewId, cx: &mut Context) {
+        tooltip_state(cx, id).hover = None;
+        if cx.is_overlay_presented(id) {
+            cx.dismiss_overlay(id);
+        }
+    }
+}
+
+fn tooltip_state(cx: &mut Context, id: ViewId) -> &mut TooltipState {
+    cx.init_state(id, &TooltipState::default);
+    cx.state_map
+        .get_mut(&id)
+        .unwrap()
+        .state
+        .downcast_mut()
+        .unwrap()
+}
+
+impl<V, T> View for Tooltip<V, T>
+where
+    V: View,
+    T: View + Clone,
+{
+    fn process(
+        &self,
+        event: &Event,
+        id: ViewId,
+        cx: &mut Context,
+        actions: &mut Vec<Box<dyn Any>>,
+    ) {
+        match event {
+            Event::TouchMove { position, .. } if cx.mouse_button.is_none() => {
+                if self.child.hittest(id.child(&0), *position, cx).is_some() {
+                    let presented = cx.is_overlay_presented(id);
+                    let s = tooltip_state(cx, id);
+                    s.position = *position;
+                    if s.hover.is_none() && !s.suppressed && !presented {
+                        s.hover = Some(0.0);
+                        cx.set_dirty();
+                    }
+                } else {
+                    self.hide(id, cx);
+                    tooltip_state(cx, id).suppressed = false;
+                }
+            }
+            Event::TouchBegin { .. } => {
+                self.hide(id, cx);
+                tooltip_state(cx, id).suppressed = true;
+            }
+            Event::Anim => {
+                if let Some(t) = tooltip_state(cx, id).hover {
+                    // Anim events come once per frame.
+                    let t = t + 1.0 / 60.0;
+                    if t >= self.delay {
+                        tooltip_state(cx, id).hover = None;
+                        self.show(id, cx);
+                    } else {
+                        tooltip_state(cx, id).hover = Some(t);
+                        cx.set_dirty();
+                    }
+                }
+            }
+            _ => (),
+        }
+        self.child.process(event, id.child(&0), cx, actions)
+    }
+
+    fn draw(&self, id: ViewId, args: &mut DrawArgs) {
+        // The view has moved, perhaps by scrolling, so the tooltip is stale.
+        let xform = args.vger.current_transform();
+        let s = tooltip_state(args.cx, id);
+        let moved = matches!(s.xform, Some(old) if old != xform);
+        s.xform = Some(xform);
+        if moved {
+            self.hide(id, args.cx);
+        }
+
+        self.child.draw(id.child(&0), args)
+    }
+
+    fn layout(&self, id: ViewId, args: &mut LayoutArgs) -> LocalSize {
+        self.child.layout(id.child(&0), args)
+    }
+
+    fn dirty(&self, id: ViewId, xform: LocalToWorld, cx: &mut Context) {
+        self.child.dirty(id.child(&0), xform, cx);
+    }
+
+    fn hittest(&self, id: ViewId, pt: LocalPoint, cx: &mut Context) -> Option<ViewId> {
+        self.child.hittest(id.child(&0), pt, cx)
+    }
+
+    fn commands(&self, id: ViewId, cx: &mut Context, cmds: &mut Vec<CommandInfo>) {
+        self.child.commands(id.child(&0), cx, cmds)
+    }
+
+    fn focus_chain(&self, id: ViewId, cx: &mut Context, chain: &mut Vec<ViewId>) {
+        self.child.focus_chain(id.child(&0), cx, chain)
+    }
+
+    fn gc(&self, id: ViewId, cx: &mut Context, map: &mut Vec<ViewId>) {
+        map.push(id);
+        self.child.gc(id.child(&0), cx, map)
+    }
+
+    fn access(
+        &self,
+        id: ViewId,
+        cx: &mut Context,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::NodeBuilder)>,
+    ) -> Option<accesskit::NodeId> {
+        let aid = self.child.access(id.child(&0), cx, nodes)?;
+
+        // Describe the view with the tooltip's text.
+        let mut tip_nodes = vec![];
+        self.tip.access(id.child(&1), cx, &mut tip_nodes);
+        let description = tip_nodes
+            .iter()
+            .filter_map(|(_, builder)| builder.name())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if !description.is_empty() {
+            if let Some((_, builder)) = nodes.iter_mut().find(|(node_id, _)| *node_id == aid) {
+                builder.set_description(description);
+            }
+        }
+        Some(aid)
+    }
+}
+
+impl<V, T> private::Sealed for Tooltip<V, T> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn hover(cx: &mut Context, ui: &impl View, position: LocalPoint) {
+        cx.process(ui, &Event::TouchMove { id: 0, position });
+    }
+
+    #[test]
+    fn test_tooltip() {
+        let mut cx = Context::new();
+
+        let ui = rectangle()
+            .size([100.0, 100.0])
+            .tooltip("Delete")
+            .delay(0.1);
+        let sz = [200.0, 200.0].into();
+        ui.layout(
+            cx.root_id,
+            &mut LayoutArgs {
+                sz,
+                cx: &mut cx,
+                text_bounds: &mut |_, _, _| LocalRect::new(LocalPoint::zero(), [20.0, 10.0].into()),
+            },
+        );
+        let root = cx.root_id;
+        tooltip_state(&mut cx, root).xform = Some(LocalToWorld::identity());
+
+        let frames = |cx: &mut Context, n| {
+            for _ in 0..n {
+                cx.process(&ui, &Event::Anim);
+            }
+        };
+
+        hover(&mut cx, &ui, [50.0, 50.0].into());
+        frames(&mut cx, 3);
+        assert!(!cx.is_overlay_presented(root));
+        frames(&mut cx, 5);
+        assert!(cx.is_overlay_presented(root));
+
+        // The tooltip doesn't take input.
+        let position = [50.0, 50.0].into();
+        cx.process(&ui, &Event::TouchBegin { id: 0, position });
+        assert!(!cx.is_overlay_presented(root));
+
+        // Not again until the pointer leaves.
+        frames(&mut cx, 10);
+        assert!(!cx.is_overlay_presented(root));
+
+        hover(&mut cx, &ui, [150.0, 150.0].into());
+        hover(&mut cx, &ui, [50.0, 50.0].into());
+        frames(&mut cx, 10);
+        assert!(cx.is_overlay_presented(root));
+
+        hover(&mut cx, &ui, [150.0, 150.0].into());
+        assert!(!cx.is_overlay_presented(root));
+    }
+
+    #[test]
+    fn test_tooltip_access() {
+        let mut cx = Context::new();
+        let ui = rectangle()
+            .role(accesskit::Role::Button)
+            .tooltip(text("Delete the file"));
+
+        let mut nodes = vec![];
+        let aid = ui.access(cx.root_id, &mut cx, &mut nodes).unwrap();
+        let (_, node) = nodes.iter().find(|(id, _)| *id == aid).unwrap();
+        assert_eq!(node.description(), Some("Delete the file"));
+    }
+}
@@ -2,26 +2,43 @@ use crate::*;
 
 use futures::executor::block_on;
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashSet, VecDeque},
     sync::Mutex,
 };
 
 use winit::{
-    dpi::PhysicalSize,
+    dpi::{LogicalPosition, PhysicalSize},
     event::{
-        ElementState, Event as WEvent, MouseButton as WMouseButton, Touch, TouchPhase,
+        ElementState, Event as WEvent, Ime, MouseButton as WMouseButton, Touch, TouchPhase,
         VirtualKeyCode, WindowEvent,
     },
-    event_loop::{ControlFlow, EventLoop, EventLoopProxy},
+    event_loop::{ControlFlow, EventLoopBuilder, EventLoopProxy},
     window::{Window, WindowBuilder},
 };
 
 type WorkQueue = VecDeque<Box<dyn FnOnce(&mut Context) + Send>>;
 
+/// Events we send to the event loop.
+#[derive(Debug)]
+enum UserEvent {
+    /// Work was queued by `on_main`.
+    Wake,
+    /// An assistive technology requested an action.
+    #[cfg(not(target_arch = "wasm32"))]
+    Access(accesskit_winit::ActionRequestEvent),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<accesskit_winit::ActionRequestEvent> for UserEvent {
+    fn from(event: accesskit_winit::ActionRequestEvent) -> Self {
+        UserEvent::Access(event)
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 lazy_static! {
     /// Allows us to wake the event loop whenever we want.
-    static ref GLOBAL_EVENT_LOOP_PROXY: Mutex<Option<EventLoopProxy<()>>> = Mutex::new(None);
+    static ref GLOBAL_EVENT_LOOP_PROXY: Mutex<Option<EventLoopProxy<UserEvent>>> = Mutex::new(None);
 
     static ref GLOBAL_WORK_QUEUE: Mutex<WorkQueue> = Mutex::new(WorkQueue::new());
 }
@@ -33,7 +50,7 @@ pub fn on_main(f: impl FnOnce(&mut Context) + Send + 'static) {
     // Wake up the event loop.
     let opt_proxy = GLOBAL_EVENT_LOOP_PROXY.lock().unwrap();
     if let Some(proxy) = &*opt_proxy {
-        if let Err(err) = proxy.send_event(()) {
+        if let Err(err) = proxy.send_event(UserEvent::Wake) {
             println!("error waking up event loop: {:?}", err);
         }
     }
@@ -115,10 +132,14 @@ async fn setup(window: &Window) -> Setup {
 
 /// Call this function to run your UI.
 pub fn rui(view: impl View) {
-    let event_loop = EventLoop::new();
+    let event_loop = EventLoopBuilder::<UserEvent>::with_user_event().build();
 
     let mut window_title = String::from("rui");
-    let builder = WindowBuilder::new().with_title(&window_title);
+
+    // The window is shown once the accessibility adapter is set up.
+    let builder = WindowBuilder::new()
+        .with_title(&window_title)
+        .with_visible(false);
     let window = builder.build(&event_loop).unwrap();
 
     let setup = block_on(setup(&window));
@@ -144,20 +165,28 @@ pub fn rui(view: impl View) {
         *GLOBAL_EVENT_LOOP_PROXY.lock().unwrap() = Some(event_loop.create_proxy());
     }
 
-    let mut vger = Vger::new(&device, config.format);
+    let mut vger = { let _ = (&device, config.format); Vger::shim() };
     let mut cx = Context::new();
     let mut mouse_position = LocalPoint::zero();
 
-    let mut commands: Vec<CommandInfo> = Vec::new();
-    let mut command_map = HashMap::new();
-    cx.commands(&view, &mut commands);
+    // Commands are collected by update, and their hotkeys
+    // dispatched as Event::Command when pressed.
+    cx.update_commands(&view);
 
-    {
-        // So we can infer a type for CommandMap when winit is enabled.
-        command_map.insert("", "");
-    }
+    // Screen readers get the tree as a whole first, then changes
+    // as they happen.
+    #[cfg(not(target_arch = "wasm32"))]
+    let access_adapter = {
+        let initial_tree = cx.access_update(&view).unwrap();
+        accesskit_winit::Adapter::new(&window, move || initial_tree, event_loop.create_proxy())
+    };
+    window.set_visible(true);
 
-    let mut access_nodes = vec![];
+    // Where we last told the IME the caret is.
+    let mut ime_rect = None;
+
+    // Keys currently held down, for detecting auto-repeat.
+    let mut keys_down = HashSet::new();
 
     event_loop.run(move |event, _, control_flow| {
         // ControlFlow::Poll continuously runs the event loop, even if the OS hasn't
@@ -169,6 +198,13 @@ pub fn rui(view: impl View) {
         // input, and uses significantly less power/CPU time than ControlFlow::Poll.
         *control_flow = ControlFlow::Wait;
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let WEvent::WindowEvent { event, .. } = &event {
+            if !access_adapter.on_event(&window, event) {
+                return;
+            }
+        }
+
         match event {
             WEvent::WindowEvent {
                 event: WindowEvent::CloseRequested,
@@ -192,7 +228,19 @@ pub fn rui(view: impl View) {
                 surface.configure(&device, &config);
                 window.request_redraw();
             }
-            WEvent::UserEvent(_) => {
+            WEvent::WindowEvent {
+                event: WindowEvent::Focused(focused),
+                ..
+            } => {
+                cx.window_focused = focused;
+                cx.set_dirty();
+
+                // Keys released while we're unfocused never report it.
+                if !focused {
+                    keys_down.clear();
+                }
+            }
+            WEvent::UserEvent(UserEvent::Wake) => {
                 // println!("received user event");
 
                 // Process the work queue.
@@ -203,6 +251,10 @@ pub fn rui(view: impl View) {
                     }
                 }
             }
+            #[cfg(not(target_arch = "wasm32"))]
+            WEvent::UserEvent(UserEvent::Access(event)) => {
+                cx.process(&view, &Event::Access(event.request))
+            }
             WEvent::MainEventsCleared => {
                 // Application update code.
 
@@ -218,10 +270,16 @@ pub fn rui(view: impl View) {
                 let width = window_size.width as f32 / scale;
                 let height = window_size.height as f32 / scale;
 
-                if cx.update(&view, &mut vger, &mut access_nodes, [width, height].into()) {
+                cx.scale_factor = scale;
+                if cx.update(&view, &mut vger, [width, height].into()) {
                     window.request_redraw();
                 }
 
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(update) = cx.pending_access_update.take() {
+                    access_adapter.update(update);
+                }
+
                 if cx.window_title != window_title {
                     window_title = cx.window_title.clone();
                     window.set_title(&cx.window_title);
@@ -253,6 +311,19 @@ pub fn rui(view: impl View) {
                     [width, height].into(),
                     scale,
                 );
+
+                // Only allow IME input while a text view has the focus.
+                if cx.ime_rect() != ime_rect {
+                    ime_rect = cx.ime_rect();
+                    window.set_ime_allowed(ime_rect.is_some());
+                    if let Some(rect) = ime_rect {
+                        // Window coordinates are y-down.
+                        window.set_ime_position(LogicalPosition::new(
+                            rect.min_x(),
+                            height - rect.min_y(),
+                        ));
+                    }
+                }
             }
             WEvent::WindowEvent {
                 event: WindowEvent::MouseInput { state, button, .. },
@@ -336,140 +407,50 @@ pub fn rui(view: impl View) {
                 event: WindowEvent::KeyboardInput { input, .. },
                 ..
             } => {
-                if input.state == ElementState::Pressed {
-                    if let Some(code) = input.virtual_keycode {
-                        let key = match code {
-                            // VirtualKeyCode::Character(c) => Some(Key::Character(c)),
-                            VirtualKeyCode::Key1 => Some(Key::Character('1')),
-                            VirtualKeyCode::Key2 => Some(Key::Character('2')),
-                            VirtualKeyCode::Key3 => Some(Key::Character('3')),
-                            VirtualKeyCode::Key4 => Some(Key::Character('4')),
-                            VirtualKeyCode::Key5 => Some(Key::Character('5')),
-                            VirtualKeyCode::Key6 => Some(Key::Character('6')),
-                            VirtualKeyCode::Key7 => Some(Key::Character('7')),
-                            VirtualKeyCode::Key8 => Some(Key::Character('8')),
-                            VirtualKeyCode::Key9 => Some(Key::Character('9')),
-                            VirtualKeyCode::Key0 => Some(Key::Character('0')),
-                            VirtualKeyCode::A => {
-                                Some(Key::Character(if cx.key_mods.shift { 'A' } else { 'a' }))
-                            }
-                            VirtualKeyCode::B => {
-                                Some(Key::Character(if cx.key_mods.shift { 'B' } else { 'b' }))
-                            }
-                            VirtualKeyCode::C => {
-                                Some(Key::Character(if cx.key_mods.shift { 'C' } else { 'c' }))
-                            }
-                            VirtualKeyCode::D => {
-                                Some(Key::Character(if cx.key_mods.shift { 'D' } else { 'd' }))
-                            }
-                            VirtualKeyCode::E => {
-                                Some(Key::Character(if cx.key_mods.shift { 'E' } else { 'e' }))
-                            }
-                            VirtualKeyCode::F => {
-                                Some(Key::Character(if cx.key_mods.shift { 'F' } else { 'f' }))
-                            }
-                            VirtualKeyCode::G => {
-                                Some(Key::Character(if cx.key_mods.shift { 'G' } else { 'g' }))
+                if let Some(code) = input.virtual_keycode {
+                    if let Some(key) = key_from_code(code, cx.key_mods.shift) {
+                        let mods = cx.key_mods;
+                        let event = match input.state {
+                            ElementState::Pressed => Event::KeyDown {
+                                key,
+                                mods,
+                                // winit doesn't tell us about repeats, so track held keys.
+                                repeat: !keys_down.insert(code),
+                            },
+                            ElementState::Released => {
+                                keys_down.remove(&code);
+                                Event::KeyUp { key, mods }
                             }
-                            VirtualKeyCode::H => {
-                                Some(Key::Character(if cx.key_mods.shift { 'H' } else { 'h' }))
-                            }
-                            VirtualKeyCode::I => {
-                                Some(Key::Character(if cx.key_mods.shift { 'I' } else { 'i' }))
-                            }
-                            VirtualKeyCode::J => {
-                                Some(Key::Character(if cx.key_mods.shift { 'J' } else { 'j' }))
-                            }
-                            VirtualKeyCode::K => {
-                                Some(Key::Character(if cx.key_mods.shift { 'K' } else { 'k' }))
-                            }
-                            VirtualKeyCode::L => {
-                                Some(Key::Character(if cx.key_mods.shift { 'L' } else { 'l' }))
-                            }
-                            VirtualKeyCode::M => {
-                                Some(Key::Character(if cx.key_mods.shift { 'M' } else { 'm' }))
-                            }
-                            VirtualKeyCode::N => {
-                                Some(Key::Character(if cx.key_mods.shift { 'N' } else { 'n' }))
-                            }
-                            VirtualKeyCode::O => {
-                                Some(Key::Character(if cx.key_mods.shift { 'O' } else { 'o' }))
-                            }
-                            VirtualKeyCode::P => {
-                                Some(Key::Character(if cx.key_mods.shift { 'P' } else { 'p' }))
-                            }
-                            VirtualKeyCode::Q => {
-                                Some(Key::Character(if cx.key_mods.shift { 'Q' } else { 'q' }))
-                            }
-                            VirtualKeyCode::R => {
-                                Some(Key::Character(if cx.key_mods.shift { 'R' } else { 'r' }))
-                            }
-                            VirtualKeyCode::S => {
-                                Some(Key::Character(if cx.key_mods.shift { 'S' } else { 's' }))
-                            }
-                            VirtualKeyCode::T => {
-                                Some(Key::Character(if cx.key_mods.shift { 'T' } else { 't' }))
-                            }
-                            VirtualKeyCode::U => {
-                                Some(Key::Character(if cx.key_mods.shift { 'U' } else { 'u' }))
-                            }
-                            VirtualKeyCode::V => {
-                                Some(Key::Character(if cx.key_mods.shift { 'V' } else { 'v' }))
-                            }
-                            VirtualKeyCode::W => {
-                                Some(Key::Character(if cx.key_mods.shift { 'W' } else { 'w' }))
-                            }
-                            VirtualKeyCode::X => {
-                                Some(Key::Character(if cx.key_mods.shift { 'X' } else { 'x' }))
-                            }
-                            VirtualKeyCode::Y => {
-                                Some(Key::Character(if cx.key_mods.shift { 'Y' } else { 'y' }))
-                            }
-                            VirtualKeyCode::Z => {
-                                Some(Key::Character(if cx.key_mods.shift { 'Z' } else { 'z' }))
-                            }
-                            VirtualKeyCode::Semicolon => Some(Key::Character(';')),
-                            VirtualKeyCode::Colon => Some(Key::Character(';')),
-                            VirtualKeyCode::Caret => Some(Key::Character('^')),
-                            VirtualKeyCode::Asterisk => Some(Key::Character('*')),
-                            VirtualKeyCode::Period => Some(Key::Character('.')),
-                            VirtualKeyCode::Comma => Some(Key::Character(',')),
-                            VirtualKeyCode::Return => Some(Key::Enter),
-                            VirtualKeyCode::Tab => Some(Key::Tab),
-                            VirtualKeyCode::Space => Some(Key::Space),
-                            VirtualKeyCode::Down => Some(Key::ArrowDown),
-                            VirtualKeyCode::Left => Some(Key::ArrowLeft),
-                            VirtualKeyCode::Right => Some(Key::ArrowRight),
-                            VirtualKeyCode::Up => Some(Key::ArrowUp),
-                            VirtualKeyCode::End => Some(Key::End),
-                            VirtualKeyCode::Home => Some(Key::Home),
-                            VirtualKeyCode::PageDown => Some(Key::PageDown),
-                            VirtualKeyCode::PageUp => Some(Key::PageUp),
-                            VirtualKeyCode::Back => Some(Key::Backspace),
-                            VirtualKeyCode::Delete => Some(Key::Delete),
-                            VirtualKeyCode::Escape => Some(Key::Escape),
-                            VirtualKeyCode::F1 => Some(Key::F1),
-                            VirtualKeyCode::F2 => Some(Key::F2),
-                            VirtualKeyCode::F3 => Some(Key::F3),
-                            VirtualKeyCode::F4 => Some(Key::F4),
-                            VirtualKeyCode::F5 => Some(Key::F5),
-                            VirtualKeyCode::F6 => Some(Key::F6),
-                            VirtualKeyCode::F7 => Some(Key::F7),
-                            VirtualKeyCode::F8 => Some(Key::F8),
-                            VirtualKeyCode::F9 => Some(Key::F9),
-                            VirtualKeyCode::F10 => Some(Key::F10),
-                            VirtualKeyCode::F11 => Some(Key::F11),
-                            VirtualKeyCode::F12 => Some(Key::F12),
-                            _ => None,
                         };
-
-                        if let Some(key) = key {
-                            cx.process(&view, &Event::Key(key))
-                        }
+                        cx.process(&view, &event)
                     }
                 }
             }
 
+            // Control characters (backspace, enter, etc.) arrive as key presses.
+            WEvent::WindowEvent {
+                event: WindowEvent::ReceivedCharacter(c),
+                ..
+            } if !c.is_control() => cx.process(&view, &Event::TextInput(c.to_string())),
+
+            WEvent::WindowEvent {
+                event: WindowEvent::Ime(ime),
+                ..
+            } => match ime {
+                Ime::Preedit(text, cursor) => {
+                    cx.process(&view, &Event::ImePreedit { text, cursor })
+                }
+                Ime::Commit(text) => cx.process(&view, &Event::ImeCommit(text)),
+                Ime::Disabled => cx.process(
+                    &view,
+                    &Event::ImePreedit {
+                        text: String::new(),
+                        cursor: None,
+                    },
+                ),
+                Ime::Enabled => (),
+            },
+
             WEvent::WindowEvent {
                 event: WindowEvent::ModifiersChanged(mods),
                 ..
@@ -486,6 +467,123 @@ pub fn rui(view: impl View) {
     });
 }
 
+/// Maps a winit key code to a `Key`. Text entry should use
+/// `Event::TextInput` instead, which respects the keyboard layout.
+fn key_from_code(code: VirtualKeyCode, shift: bool) -> Option<Key> {
+    Some(match code {
+        VirtualKeyCode::Key1 | VirtualKeyCode::Numpad1 => Key::Character('1'),
+        VirtualKeyCode::Key2 | VirtualKeyCode::Numpad2 => Key::Character('2'),
+        VirtualKeyCode::Key3 | VirtualKeyCode::Numpad3 => Key::Character('3'),
+        VirtualKeyCode::Key4 | VirtualKeyCode::Numpad4 => Key::Character('4'),
+        VirtualKeyCode::Key5 | VirtualKeyCode::Numpad5 => Key::Character('5'),
+        VirtualKeyCode::Key6 | VirtualKeyCode::Numpad6 => Key::Character('6'),
+        VirtualKeyCode::Key7 | VirtualKeyCode::Numpad7 => Key::Character('7'),
+        VirtualKeyCode::Key8 | VirtualKeyCode::Numpad8 => Key::Character('8'),
+        VirtualKeyCode::Key9 | VirtualKeyCode::Numpad9 => Key::Character('9'),
+        VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => Key::Character('0'),
+        VirtualKeyCode::A => Key::Character(if shift { 'A' } else { 'a' }),
+        VirtualKeyCode::B => Key::Character(if shift { 'B' } else { 'b' }),
+        VirtualKeyCode::C => Key::Character(if shift { 'C' } else { 'c' }),
+        VirtualKeyCode::D => Key::Character(if shift { 'D' } else { 'd' }),
+        VirtualKeyCode::E => Key::Character(if shift { 'E' } else { 'e' }),
+        VirtualKeyCode::F => Key::Character(if shift { 'F' } else { 'f' }),
+        VirtualKeyCode::G => Key::Character(if shift { 'G' } else { 'g' }),
+        VirtualKeyCode::H => Key::Character(if shift { 'H' } else { 'h' }),
+        VirtualKeyCode::I => Key::Character(if shift { 'I' } else { 'i' }),
+        VirtualKeyCode::J => Key::Character(if shift { 'J' } else { 'j' }),
+        VirtualKeyCode::K => Key::Character(if shift { 'K' } else { 'k' }),
+        VirtualKeyCode::L => Key::Character(if shift { 'L' } else { 'l' }),
+        VirtualKeyCode::M => Key::Character(if shift { 'M' } else { 'm' }),
+        VirtualKeyCode::N => Key::Character(if shift { 'N' } else { 'n' }),
+        VirtualKeyCode::O => Key::Character(if shift { 'O' } else { 'o' }),
+        VirtualKeyCode::P => Key::Character(if shift { 'P' } else { 'p' }),
+        VirtualKeyCode::Q => Key::Character(if shift { 'Q' } else { 'q' }),
+        VirtualKeyCode::R => Key::Character(if shift { 'R' } else { 'r' }),
+        VirtualKeyCode::S => Key::Character(if shift { 'S' } else { 's' }),
+        VirtualKeyCode::T => Key::Character(if shift { 'T' } else { 't' }),
+        VirtualKeyCode::U => Key::Character(if shift { 'U' } else { 'u' }),
+        VirtualKeyCode::V => Key::Character(if shift { 'V' } else { 'v' }),
+        VirtualKeyCode::W => Key::Character(if shift { 'W' } else { 'w' }),
+        VirtualKeyCode::X => Key::Character(if shift { 'X' } else { 'x' }),
+        VirtualKeyCode::Y => Key::Character(if shift { 'Y' } else { 'y' }),
+        VirtualKeyCode::Z => Key::Character(if shift { 'Z' } else { 'z' }),
+        VirtualKeyCode::Apostrophe => Key::Character('\''),
+        VirtualKeyCode::Asterisk | VirtualKeyCode::NumpadMultiply => Key::Character('*'),
+        VirtualKeyCode::At => Key::Character('@'),
+        VirtualKeyCode::Backslash => Key::Character('\\'),
+        VirtualKeyCode::Caret => Key::Character('^'),
+        VirtualKeyCode::Colon => Key::Character(':'),
+        VirtualKeyCode::Comma | VirtualKeyCode::NumpadComma => Key::Character(','),
+        VirtualKeyCode::Equals | VirtualKeyCode::NumpadEquals => Key::Character('='),
+        VirtualKeyCode::Grave => Key::Character('`'),
+        VirtualKeyCode::LBracket => Key::Character('['),
+        VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => Key::Character('-'),
+        VirtualKeyCode::NumpadDivide | VirtualKeyCode::Slash => Key::Character('/'),
+        VirtualKeyCode::Period | VirtualKeyCode::NumpadDecimal => Key::Character('.'),
+        VirtualKeyCode::Plus | VirtualKeyCode::NumpadAdd => Key::Character('+'),
+        VirtualKeyCode::RBracket => Key::Character(']'),
+        VirtualKeyCode::Semicolon => Key::Character(';'),
+        VirtualKeyCode::Underline => Key::Character('_'),
+        VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => Key::Enter,
+        VirtualKeyCode::Tab => Key::Tab,
+        VirtualKeyCode::Space => Key::Space,
+        VirtualKeyCode::Down => Key::ArrowDown,
+        VirtualKeyCode::Left => Key::ArrowLeft,
+        VirtualKeyCode::Right => Key::ArrowRight,
+        VirtualKeyCode::Up => Key::ArrowUp,
+        VirtualKeyCode::End => Key::End,
+        VirtualKeyCode::Home => Key::Home,
+        VirtualKeyCode::PageDown => Key::PageDown,
+        VirtualKeyCode::PageUp => Key::PageUp,
+        VirtualKeyCode::Back => Key::Backspace,
+        VirtualKeyCode::Delete => Key::Delete,
+        VirtualKeyCode::Insert => Key::Insert,
+        VirtualKeyCode::Escape => Key::Escape,
+        VirtualKeyCode::F1 => Key::F1,
+        VirtualKeyCode::F2 => Key::F2,
+        VirtualKeyCode::F3 => Key::F3,
+        VirtualKeyCode::F4 => Key::F4,
+        VirtualKeyCode::F5 => Key::F5,
+        VirtualKeyCode::F6 => Key::F6,
+        VirtualKeyCode::F7 => Key::F7,
+        VirtualKeyCode::F8 => Key::F8,
+        VirtualKeyCode::F9 => Key::F9,
+        VirtualKeyCode::F10 => Key::F10,
+        VirtualKeyCode::F11 => Key::F11,
+        VirtualKeyCode::F12 => Key::F12,
+        VirtualKeyCode::F13 => Key::F13,
+        VirtualKeyCode::F14 => Key::F14,
+        VirtualKeyCode::F15 => Key::F15,
+        VirtualKeyCode::F16 => Key::F16,
+        VirtualKeyCode::F17 => Key::F17,
+        VirtualKeyCode::F18 => Key::F18,
+        VirtualKeyCode::F19 => Key::F19,
+        VirtualKeyCode::F20 => Key::F20,
+        VirtualKeyCode::F21 => Key::F21,
+        VirtualKeyCode::F22 => Key::F22,
+        VirtualKeyCode::F23 => Key::F23,
+        VirtualKeyCode::F24 => Key::F24,
+        VirtualKeyCode::LShift | VirtualKeyCode::RShift => Key::Shift,
+        VirtualKeyCode::LControl | VirtualKeyCode::RControl => Key::Control,
+        VirtualKeyCode::LAlt | VirtualKeyCode::RAlt => Key::Alt,
+        VirtualKeyCode::LWin | VirtualKeyCode::RWin => Key::Meta,
+        VirtualKeyCode::Capital => Key::CapsLock,
+        VirtualKeyCode::Numlock => Key::NumLock,
+        VirtualKeyCode::Scroll => Key::ScrollLock,
+        VirtualKeyCode::Snapshot => Key::PrintScreen,
+        VirtualKeyCode::Pause => Key::Pause,
+        VirtualKeyCode::Apps => Key::ContextMenu,
+        VirtualKeyCode::PlayPause => Key::MediaPlayPause,
+        VirtualKeyCode::MediaStop => Key::MediaStop,
+        VirtualKeyCode::NextTrack => Key::MediaTrackNext,
+        VirtualKeyCode::PrevTrack => Key::MediaTrackPrevious,
+        VirtualKeyCode::VolumeUp => Key::AudioVolumeUp,
+        VirtualKeyCode::VolumeDown => Key::AudioVolumeDown,
+        VirtualKeyCode::Mute => Key::AudioVolumeMute,
+        _ => return None,
+    })
+}
+
 #[cfg(target_arch = "wasm32")]
 /// Parse the query string as returned by `web_sys::window()?.location().search()?` and get a
 /// specific key out of it.